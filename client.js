@@ -8,7 +8,11 @@
 import pkg from '@coral-xyz/anchor';
 const { AnchorProvider, Program, web3, BN } = pkg;
 import { Connection, PublicKey, Keypair } from '@solana/web3.js';
-import { getAssociatedTokenAddress, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddress,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
 import facilitatorIdl from './idl.json' with { type: 'json' };
 import crypto from 'crypto';
 import dotenv from 'dotenv';
@@ -28,6 +32,62 @@ const ORACLE_AUTHORITY = process.env.ORACLE_PRIVATE_KEY
   ? Keypair.fromSecretKey(new Uint8Array(JSON.parse(process.env.ORACLE_PRIVATE_KEY)))
   : Keypair.generate();
 
+// Reputation decay, mirroring `half_life_decay` and `Node::adjust_reputation`
+// in the program. The stored score only decays when the node is touched, so
+// compare nodes on `currentReputation` instead.
+const REPUTATION_BASELINE = 5000n;
+const REPUTATION_HALF_LIFE = 30n * 86400n;
+const HALF_LIFE_ROOTS = [
+  3037000500n,
+  3611622603n,
+  3938502376n,
+  4112874773n,
+  4202935003n,
+  4248701965n,
+  4271771996n,
+  4283353945n,
+  4289156690n,
+  4292061010n,
+  4293513907n,
+  4294240540n,
+  4294603903n,
+  4294785595n,
+  4294876445n,
+  4294921870n,
+  4294944583n,
+  4294955939n,
+  4294961618n,
+  4294964457n,
+];
+
+function halfLifeDecay(value, elapsed, halfLife) {
+  if (elapsed < 0n) elapsed = 0n;
+  const halvings = elapsed / halfLife;
+  if (halvings >= 128n) return 0n;
+  let result = value >> halvings;
+  const digits = BigInt(HALF_LIFE_ROOTS.length);
+  const fraction = ((elapsed % halfLife) << digits) / halfLife;
+  HALF_LIFE_ROOTS.forEach((root, i) => {
+    if (fraction & (1n << (digits - 1n - BigInt(i)))) {
+      result = (result * root) >> 32n;
+    }
+  });
+  return result;
+}
+
+/**
+ * A node's reputation decayed to `now` (unix seconds), from the fixed-point
+ * score and timestamp stored on its account.
+ */
+function currentReputation(reputationFixed, reputationUpdatedAt, now = Date.now() / 1000) {
+  const baseline = REPUTATION_BASELINE << 32n;
+  const gap = BigInt(reputationFixed.toString()) - baseline;
+  const elapsed = BigInt(Math.floor(now)) - BigInt(reputationUpdatedAt.toString());
+  const decayed = halfLifeDecay(gap < 0n ? -gap : gap, elapsed, REPUTATION_HALF_LIFE);
+  const score = gap < 0n ? baseline - decayed : baseline + decayed;
+  return Number((score + (1n << 31n)) >> 32n);
+}
+
 class FacilitatorClient {
   constructor(connection, programId = FACILITATOR_PROGRAM_ID) {
     this.connection = connection || new Connection(
@@ -66,12 +126,42 @@ class FacilitatorClient {
     console.log(`[Facilitator] Oracle: ${this.oracleAuthority.publicKey.toString()}`);
   }
 
+  /**
+   * PDA seed for a node or intent id (ids are hashed so they may exceed
+   * the 32-byte seed limit)
+   */
+  idSeed(id) {
+    return crypto.createHash('sha256').update(id).digest();
+  }
+
+  /**
+   * Derive FacilitatorConfig PDA
+   */
+  deriveConfigPDA() {
+    const [pda, bump] = PublicKey.findProgramAddressSync(
+      [Buffer.from('config')],
+      this.programId
+    );
+    return { pda, bump };
+  }
+
+  /**
+   * Derive the event authority PDA used by instructions that emit CPI events
+   */
+  deriveEventAuthorityPDA() {
+    const [pda, bump] = PublicKey.findProgramAddressSync(
+      [Buffer.from('__event_authority')],
+      this.programId
+    );
+    return { pda, bump };
+  }
+
   /**
    * Derive Node PDA
    */
   deriveNodePDA(nodeId) {
     const [pda, bump] = PublicKey.findProgramAddressSync(
-      [Buffer.from('node'), Buffer.from(nodeId)],
+      [Buffer.from('node'), this.idSeed(nodeId)],
       this.programId
     );
     return { pda, bump };
@@ -82,7 +172,7 @@ class FacilitatorClient {
    */
   derivePaymentIntentPDA(intentId) {
     const [pda, bump] = PublicKey.findProgramAddressSync(
-      [Buffer.from('intent'), Buffer.from(intentId)],
+      [Buffer.from('intent'), this.idSeed(intentId)],
       this.programId
     );
     return { pda, bump };
@@ -93,48 +183,104 @@ class FacilitatorClient {
    */
   deriveUsageProofPDA(intentId) {
     const [pda, bump] = PublicKey.findProgramAddressSync(
-      [Buffer.from('proof'), Buffer.from(intentId)],
+      [Buffer.from('proof'), this.idSeed(intentId)],
       this.programId
     );
     return { pda, bump };
   }
 
+  /**
+   * Derive an oracle's OracleAccount PDA from its authority key
+   */
+  deriveOracleAccountPDA(authority) {
+    const [pda, bump] = PublicKey.findProgramAddressSync(
+      [Buffer.from('oracle'), authority.toBuffer()],
+      this.programId
+    );
+    return { pda, bump };
+  }
+
+  /**
+   * Derive the program's reward vault, treasury and insurance vault PDAs
+   */
+  deriveProtocolVaultPDAs() {
+    const derive = (seed) => PublicKey.findProgramAddressSync(
+      [Buffer.from(seed)],
+      this.programId
+    )[0];
+    return {
+      rewardVault: derive('reward_vault'),
+      treasury: derive('treasury'),
+      insuranceVault: derive('insurance'),
+    };
+  }
+
   /**
    * Register a node in the Facilitator
+   *
+   * profile: { nodeId, endpoint, region, hardware: { cpuCores, ramGb,
+   * gpuModel, gpuCount, vramGb } }. A non-zero stakeAmount is staked from
+   * the owner's HYPER associated token account right after registration.
    */
-  async registerNode(authorityPubkey, nodeId, stakeAmount = 0) {
+  async registerNode(ownerPubkey, profile, stakeAmount = 0) {
     try {
-      console.log(`[Facilitator] Registering node: ${nodeId}`);
+      console.log(`[Facilitator] Registering node: ${profile.nodeId}`);
 
-      const authority = new PublicKey(authorityPubkey);
-      const { pda: nodeAccount } = this.deriveNodePDA(nodeId);
+      const owner = new PublicKey(ownerPubkey);
+      const { pda: nodeAccount } = this.deriveNodePDA(profile.nodeId);
 
-      // Get token accounts
-      const stakingAccount = await getAssociatedTokenAddress(
-        HYPER_MINT,
-        nodeAccount,
-        true // allowOwnerOffCurve
+      const [operatorAccount] = PublicKey.findProgramAddressSync(
+        [Buffer.from('operator'), owner.toBuffer()],
+        this.programId
+      );
+      const [vault] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vault'), nodeAccount.toBuffer()],
+        this.programId
       );
 
       const tx = await this.program.methods
-        .registerNode(nodeId, new BN(stakeAmount))
+        .registerNode(profile)
         .accounts({
           node: nodeAccount,
-          authority,
-          stakingAccount,
+          operatorAccount,
+          vault,
+          config: this.deriveConfigPDA().pda,
+          user: owner,
           hyperMint: HYPER_MINT,
           systemProgram: web3.SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          eventAuthority: this.deriveEventAuthorityPDA().pda,
+          program: this.programId,
         })
         .rpc();
 
       console.log(`[Facilitator] Node registered. TX: ${tx}`);
 
+      let stakeTx = null;
+      if (new BN(stakeAmount.toString()).gtn(0)) {
+        stakeTx = await this.program.methods
+          .stake(new BN(stakeAmount.toString()))
+          .accounts({
+            node: nodeAccount,
+            vault,
+            config: this.deriveConfigPDA().pda,
+            ownerTokenAccount: await getAssociatedTokenAddress(HYPER_MINT, owner),
+            owner,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            eventAuthority: this.deriveEventAuthorityPDA().pda,
+            program: this.programId,
+          })
+          .rpc();
+
+        console.log(`[Facilitator] Node staked. TX: ${stakeTx}`);
+      }
+
       return {
         success: true,
         nodeAccount: nodeAccount.toString(),
-        stakingAccount: stakingAccount.toString(),
+        vault: vault.toString(),
         txSignature: tx,
+        stakeTxSignature: stakeTx,
       };
 
     } catch (error) {
@@ -145,8 +291,11 @@ class FacilitatorClient {
 
   /**
    * Create and authorize a payment intent (x402 protocol)
+   *
+   * A non-zero disputeWindow (seconds) holds the escrow after verification
+   * so the client can still open a dispute.
    */
-  async authorizePayment(clientPubkey, intentId, amount, expiresAt) {
+  async authorizePayment(clientPubkey, intentId, amount, expiresAt, disputeWindow = 0) {
     try {
       console.log(`[Facilitator] Authorizing payment: ${intentId}`);
 
@@ -155,7 +304,7 @@ class FacilitatorClient {
 
       // Get escrow PDA
       const [escrow] = PublicKey.findProgramAddressSync(
-        [Buffer.from('escrow'), Buffer.from(intentId)],
+        [Buffer.from('escrow'), this.idSeed(intentId)],
         this.programId
       );
 
@@ -165,17 +314,12 @@ class FacilitatorClient {
         client
       );
 
-      const escrowTokenAccount = await getAssociatedTokenAddress(
-        HYPER_MINT,
-        escrow,
-        true
-      );
-
       // Create payment intent data
       const intentData = {
         intentId,
-        amount: new BN(amount),
+        amount: new BN(amount.toString()),
         expiresAt: new BN(expiresAt),
+        disputeWindow: new BN(disputeWindow),
       };
 
       const tx = await this.program.methods
@@ -185,8 +329,12 @@ class FacilitatorClient {
           client,
           escrow,
           clientTokenAccount,
-          escrowTokenAccount,
+          config: this.deriveConfigPDA().pda,
+          hyperMint: HYPER_MINT,
+          systemProgram: web3.SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          eventAuthority: this.deriveEventAuthorityPDA().pda,
+          program: this.programId,
         })
         .rpc();
 
@@ -208,28 +356,31 @@ class FacilitatorClient {
 
   /**
    * Submit usage proof from Oracle
-   * This releases payment to the node
+   *
+   * The escrow is released to the node once the oracle quorum has attested
+   * (or held for the intent's dispute window). The earlier attestors'
+   * OracleAccounts are passed along so each is credited its fee share.
    */
   async submitUsageProof(intentId, nodeId, executionHash, logsHash) {
     try {
       console.log(`[Facilitator] Submitting usage proof for: ${intentId}`);
 
+      const oracle = this.oracleAuthority.publicKey;
       const { pda: usageProof } = this.deriveUsageProofPDA(intentId);
       const { pda: paymentIntent } = this.derivePaymentIntentPDA(intentId);
       const { pda: nodeAccount } = this.deriveNodePDA(nodeId);
 
       // Get escrow
       const [escrow] = PublicKey.findProgramAddressSync(
-        [Buffer.from('escrow'), Buffer.from(intentId)],
+        [Buffer.from('escrow'), this.idSeed(intentId)],
         this.programId
       );
 
-      // Get node token account
-      const nodeTokenAccount = await getAssociatedTokenAddress(
-        HYPER_MINT,
-        nodeAccount,
-        true
-      );
+      const intent = await this.program.account.paymentIntent.fetch(paymentIntent);
+      const proof = await this.program.account.usageProof.fetchNullable(usageProof);
+      const attestors = proof && proof.intentCreatedAt.eq(intent.createdAt)
+        ? proof.attestors.filter((attestor) => !attestor.equals(oracle))
+        : [];
 
       const proofData = {
         intentId,
@@ -244,11 +395,22 @@ class FacilitatorClient {
           usageProof,
           paymentIntent,
           node: nodeAccount,
-          oracle: this.oracleAuthority.publicKey,
+          config: this.deriveConfigPDA().pda,
+          oracleAccount: this.deriveOracleAccountPDA(oracle).pda,
+          oracle,
           escrow,
-          nodeTokenAccount,
+          ...this.deriveProtocolVaultPDAs(),
+          client: intent.client,
+          systemProgram: web3.SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          eventAuthority: this.deriveEventAuthorityPDA().pda,
+          program: this.programId,
         })
+        .remainingAccounts(attestors.map((attestor) => ({
+          pubkey: this.deriveOracleAccountPDA(attestor).pda,
+          isWritable: true,
+          isSigner: false,
+        })))
         .signers([this.oracleAuthority])
         .rpc();
 
@@ -267,34 +429,34 @@ class FacilitatorClient {
   }
 
   /**
-   * Claim rewards (node owner withdraws earnings)
+   * Claim rewards (node owner withdraws earnings from the reward vault
+   * into their HYPER associated token account)
    */
-  async claimRewards(authorityPubkey, nodeId, amount) {
+  async claimRewards(ownerPubkey, nodeId, amount) {
     try {
       console.log(`[Facilitator] Claiming rewards for node: ${nodeId}`);
 
-      const authority = new PublicKey(authorityPubkey);
+      const owner = new PublicKey(ownerPubkey);
       const { pda: nodeAccount } = this.deriveNodePDA(nodeId);
 
-      const stakingAccount = await getAssociatedTokenAddress(
+      const ownerTokenAccount = await getAssociatedTokenAddress(
         HYPER_MINT,
-        nodeAccount,
-        true
-      );
-
-      const authorityTokenAccount = await getAssociatedTokenAddress(
-        HYPER_MINT,
-        authority
+        owner
       );
 
       const tx = await this.program.methods
         .claimRewards(new BN(amount))
         .accounts({
           node: nodeAccount,
-          authority,
-          stakingAccount,
-          authorityTokenAccount,
+          config: this.deriveConfigPDA().pda,
+          rewardVault: this.deriveProtocolVaultPDAs().rewardVault,
+          ownerTokenAccount,
+          owner,
+          hyperMint: HYPER_MINT,
           tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          eventAuthority: this.deriveEventAuthorityPDA().pda,
+          program: this.programId,
         })
         .rpc();
 
@@ -318,16 +480,29 @@ class FacilitatorClient {
   async getNodeAccount(nodeId) {
     try {
       const { pda } = this.deriveNodePDA(nodeId);
-      const account = await this.program.account.nodeAccount.fetch(pda);
+      const account = await this.program.account.node.fetch(pda);
 
       return {
         address: pda.toString(),
-        authority: account.authority.toString(),
+        owner: account.owner.toString(),
+        operator: account.operator.toString(),
         nodeId: account.nodeId,
-        stakeAmount: account.stakeAmount.toString(),
+        endpoint: account.endpoint,
+        region: account.region,
+        stakedAmount: account.stakedAmount.toString(),
+        unbondingAmount: account.unbondingAmount.toString(),
+        pendingReward: account.pendingReward.toString(),
         totalEarned: account.totalEarned.toString(),
         jobsCompleted: account.jobsCompleted.toString(),
         isActive: account.isActive,
+        jailed: account.jailed,
+        reputation: account.reputation,
+        currentReputation: currentReputation(
+          account.reputationFixed,
+          account.reputationUpdatedAt
+        ),
+        reputationUpdatedAt: new Date(account.reputationUpdatedAt.toNumber() * 1000),
+        uptimeEpochs: account.uptimeEpochs.toNumber(),
         registeredAt: new Date(account.registeredAt.toNumber() * 1000),
       };
 
@@ -355,6 +530,8 @@ class FacilitatorClient {
         status: Object.keys(account.status)[0], // enum to string
         createdAt: new Date(account.createdAt.toNumber() * 1000),
         expiresAt: new Date(account.expiresAt.toNumber() * 1000),
+        disputeWindow: account.disputeWindow.toNumber(),
+        node: account.node ? account.node.toString() : null,
       };
 
     } catch (error) {
@@ -405,4 +582,10 @@ function getFacilitatorClient() {
 }
 
 export default getFacilitatorClient();
-export { FacilitatorClient, FACILITATOR_PROGRAM_ID, HYPER_MINT, getFacilitatorClient };
+export {
+  FacilitatorClient,
+  FACILITATOR_PROGRAM_ID,
+  HYPER_MINT,
+  getFacilitatorClient,
+  currentReputation,
+};
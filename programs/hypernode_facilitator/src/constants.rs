@@ -1 +1,27 @@
-pub const STAKE_MINIMUM: u64 = 1_000_000_000; // 1 HYPER in lamports
+/// Ids are hashed into PDA seeds (see `utils::id_seed`), so these only bound
+/// the stored strings; keep them in sync with the `max_len` on the accounts.
+pub const INTENT_ID_MAX_LEN: usize = 64;
+pub const NODE_ID_MAX_LEN: usize = 64;
+pub const ENDPOINT_MAX_LEN: usize = 128;
+pub const REGION_MAX_LEN: usize = 32;
+pub const GPU_MODEL_MAX_LEN: usize = 32;
+/// Hard ceiling for `FacilitatorConfig::max_payment_timeout`.
+pub const MAX_PAYMENT_TIMEOUT: i64 = 86400; // 24 hours
+/// Hard ceiling for `FacilitatorConfig::max_dispute_window`.
+pub const MAX_DISPUTE_WINDOW: i64 = 7 * 86400; // 7 days
+pub const BPS_DENOMINATOR: u64 = 10_000;
+/// Upper bound for `FacilitatorConfig::oracle_quorum`.
+pub const MAX_ATTESTATIONS: usize = 5;
+/// Node reputation is kept in `0..=REPUTATION_MAX`. New nodes start at the
+/// baseline, and scores decay back to it exponentially, halving the gap
+/// every `REPUTATION_HALF_LIFE` seconds.
+pub const REPUTATION_MAX: u16 = 10_000;
+pub const REPUTATION_BASELINE: u16 = 5_000;
+pub const REPUTATION_HALF_LIFE: i64 = 30 * 86400; // 30 days
+pub const REPUTATION_JOB_COMPLETED: i32 = 50;
+pub const REPUTATION_DISPUTE_LOST: i32 = -500;
+pub const REPUTATION_SLASHED: i32 = -1_000;
+pub const REPUTATION_UPTIME: i32 = 5;
+/// Size of the arbitration council; keep in sync with the `max_len` on
+/// `FacilitatorConfig::arbiters`.
+pub const MAX_ARBITERS: usize = 5;
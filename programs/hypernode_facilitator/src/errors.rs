@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum FacilitatorError {
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+
+    #[msg("Signer is not the config admin")]
+    Unauthorized,
+
+    #[msg("Signer is not the node owner")]
+    NotNodeOwner,
+
+    #[msg("Account does not belong to the payment intent's client")]
+    InvalidClient,
+
+    #[msg("Invalid configuration value")]
+    InvalidConfig,
+
+    #[msg("Unstake amount exceeds active stake")]
+    InsufficientStake,
+
+    #[msg("No unbonding stake to withdraw")]
+    NothingToWithdraw,
+
+    #[msg("Unbonding period has not elapsed")]
+    UnbondingInProgress,
+
+    #[msg("Intent ID must be between 1 and 64 bytes")]
+    InvalidIntentId,
+
+    #[msg("Payment amount must be greater than zero")]
+    InvalidPaymentAmount,
+
+    #[msg("Payment intent has expired")]
+    PaymentExpired,
+
+    #[msg("Payment expiry exceeds the maximum payment timeout")]
+    InvalidExpiry,
+
+    #[msg("Payment intent is not in the required status")]
+    InvalidPaymentStatus,
+
+    #[msg("Unauthorized oracle")]
+    UnauthorizedOracle,
+
+    #[msg("Invalid execution hash format")]
+    InvalidExecutionHash,
+
+    #[msg("Invalid logs hash format")]
+    InvalidLogsHash,
+
+    #[msg("Node stake is below the minimum required to take work")]
+    InsufficientNodeStake,
+
+    #[msg("Insufficient balance for withdrawal")]
+    InsufficientBalance,
+
+    #[msg("Payment cannot be cancelled in current status")]
+    CannotCancel,
+
+    #[msg("Payment intent has not expired yet")]
+    PaymentNotExpired,
+
+    #[msg("Facilitator is paused")]
+    ProgramPaused,
+
+    #[msg("Token mint does not match the configured HYPER mint")]
+    InvalidMint,
+
+    #[msg("Node ID must be between 1 and 64 bytes")]
+    InvalidNodeId,
+
+    #[msg("Node endpoint must be between 1 and 128 bytes")]
+    InvalidEndpoint,
+
+    #[msg("Node profile field exceeds its maximum length")]
+    InvalidNodeProfile,
+
+    #[msg("Node ID cannot be changed after registration")]
+    NodeIdImmutable,
+
+    #[msg("Node is not active")]
+    NodeInactive,
+
+    #[msg("Node is already active")]
+    NodeAlreadyActive,
+
+    #[msg("Node still holds stake or rewards")]
+    NodeNotEmpty,
+
+    #[msg("Signer is not the node operator")]
+    NotNodeOperator,
+
+    #[msg("Payment intent is already assigned to a node")]
+    IntentAlreadyAssigned,
+
+    #[msg("Node does not match the node assigned to the payment intent")]
+    NodeMismatch,
+
+    #[msg("Signer is not the pending owner of this node")]
+    NotPendingOwner,
+
+    #[msg("New owner must differ from the current owner")]
+    InvalidNewOwner,
+
+    #[msg("Oracle key has already been rotated out")]
+    OracleRetired,
+
+    #[msg("Oracle has already attested to this usage proof")]
+    DuplicateAttestation,
+
+    #[msg("Oracle bond is below the configured minimum")]
+    InsufficientOracleBond,
+
+    #[msg("Oracle bond is locked until the key is suspended or retired and unbonding has passed")]
+    OracleBondLocked,
+
+    #[msg("Oracle still holds a bond or unclaimed fees")]
+    OracleNotEmpty,
+
+    #[msg("Oracle account for an attestor is missing")]
+    MissingOracleAccount,
+
+    #[msg("Dispute window exceeds the configured maximum")]
+    InvalidDisputeWindow,
+
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+
+    #[msg("Invalid evidence hash format")]
+    InvalidEvidenceHash,
+
+    #[msg("Signer is not an arbiter")]
+    NotArbiter,
+
+    #[msg("Split exceeds 10000 basis points")]
+    InvalidSplit,
+
+    #[msg("Invalid resolution hash format")]
+    InvalidResolutionHash,
+
+    #[msg("Node has no stake to slash")]
+    NothingToSlash,
+
+    #[msg("Compensation would exceed the payment amount")]
+    CompensationExceeded,
+
+    #[msg("Node is jailed")]
+    NodeJailed,
+
+    #[msg("Node is not jailed")]
+    NodeNotJailed,
+
+    #[msg("Jail cooldown has not elapsed")]
+    JailCooldownActive,
+
+    #[msg("Account of the node assigned to this intent is missing")]
+    MissingNodeAccount,
+
+    #[msg("Node has missed too many heartbeats")]
+    NodeOffline,
+
+    #[msg("Node has not missed enough heartbeats to be marked offline")]
+    HeartbeatCurrent,
+
+    #[msg("Node still has assigned jobs awaiting settlement or refund")]
+    NodeHasOpenJobs,
+
+    #[msg("Intent has already been used to slash its node")]
+    SlashEvidenceUsed,
+
+    #[msg("Client can only be compensated when the node lost the dispute")]
+    NodeWonDispute,
+
+    #[msg("Oracle key's predecessor has not retired yet")]
+    RotationInProgress,
+}
@@ -0,0 +1,322 @@
+use anchor_lang::prelude::*;
+
+use crate::state::OracleStatus;
+
+#[event]
+pub struct NodeRegistered {
+    pub node: Pubkey,
+    pub node_id: String,
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NodeProfileUpdated {
+    pub node: Pubkey,
+    pub endpoint: String,
+    pub region: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NodeStatusChanged {
+    pub node: Pubkey,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NodeJailed {
+    pub node: Pubkey,
+    pub owner: Pubkey,
+    pub consecutive_failures: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NodeUnjailed {
+    pub node: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReputationReason {
+    JobCompleted,
+    DisputeLost,
+    Slashed,
+    Uptime,
+}
+
+#[event]
+pub struct ReputationUpdated {
+    pub node: Pubkey,
+    pub reason: ReputationReason,
+    /// Change applied on top of the time decay.
+    pub delta: i32,
+    /// Score after decay and `delta`, out of `REPUTATION_MAX`.
+    pub reputation: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NodeClosed {
+    pub node: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnerTransferProposed {
+    pub node: Pubkey,
+    pub owner: Pubkey,
+    /// `None` when a pending proposal was withdrawn.
+    pub pending_owner: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnerTransferred {
+    pub node: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorChanged {
+    pub node: Pubkey,
+    pub operator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct JobAccepted {
+    pub payment_intent: Pubkey,
+    pub node: Pubkey,
+    pub operator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleAdded {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleStatusChanged {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    pub status: OracleStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleRemoved {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleRotated {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    /// The previous key stops being accepted at this time.
+    pub retires_at: i64,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum OracleBondChangeKind {
+    Bonded,
+    Withdrawn,
+    Slashed,
+}
+
+#[event]
+pub struct OracleBondChanged {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    pub kind: OracleBondChangeKind,
+    pub amount: u64,
+    /// Bond after the change.
+    pub bonded_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleFeesClaimed {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum StakeChangeKind {
+    Staked,
+    UnstakeRequested,
+    Withdrawn,
+}
+
+#[event]
+pub struct StakeChanged {
+    pub node: Pubkey,
+    pub owner: Pubkey,
+    pub kind: StakeChangeKind,
+    pub amount: u64,
+    /// Balances after the change.
+    pub staked_amount: u64,
+    pub unbonding_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaymentAuthorized {
+    pub payment_intent: Pubkey,
+    pub escrow: Pubkey,
+    pub intent_id: String,
+    pub client: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub dispute_window: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaymentVerified {
+    pub payment_intent: Pubkey,
+    pub usage_proof: Pubkey,
+    pub node: Pubkey,
+    /// `finalize_settlement` releases the escrow from this time on.
+    pub dispute_ends_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub payment_intent: Pubkey,
+    pub client: Pubkey,
+    pub node: Option<Pubkey>,
+    pub evidence_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub payment_intent: Pubkey,
+    pub arbiter: Pubkey,
+    pub node: Pubkey,
+    /// Escrow refunded to the client.
+    pub client_amount: u64,
+    /// Protocol fee taken from the node's share, as in `PaymentSettled`.
+    pub fee: u64,
+    /// Escrow credited to `Node.pending_reward`, after the fee.
+    pub node_amount: u64,
+    /// Stake moved to the insurance vault; see `NodeSlashed`.
+    pub slashed: u64,
+    pub resolution_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NodeSlashed {
+    pub node: Pubkey,
+    pub owner: Pubkey,
+    /// Intent whose dispute or conflicting proof the slash rests on.
+    pub payment_intent: Pubkey,
+    /// Arbiter, or whoever submitted an oracle quorum's co-signatures.
+    pub slasher: Pubkey,
+    pub amount: u64,
+    /// Balances after the slash.
+    pub staked_amount: u64,
+    pub unbonding_amount: u64,
+    pub slash_count: u32,
+    pub evidence_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClientCompensated {
+    pub payment_intent: Pubkey,
+    pub client: Pubkey,
+    pub arbiter: Pubkey,
+    pub amount: u64,
+    pub total_compensated: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaymentSettled {
+    pub payment_intent: Pubkey,
+    pub usage_proof: Pubkey,
+    pub intent_id: String,
+    pub node: Pubkey,
+    pub attestors: Vec<Pubkey>,
+    /// Full escrowed amount.
+    pub amount: u64,
+    /// Protocol cut, sent to the treasury. Includes the oracle shares of
+    /// attestors removed before settlement.
+    pub fee: u64,
+    /// Part of `fee` routed to the insurance vault instead, once the
+    /// treasury holds `insurance_fee_threshold`.
+    pub insurance_fee: u64,
+    /// Cut credited to the attestors' `OracleAccount.pending_fees`.
+    pub oracle_fee: u64,
+    /// Node share sent to the insurance vault for missed heartbeat epochs;
+    /// see `FacilitatorConfig::uptime_reward_bps`.
+    pub uptime_withheld: u64,
+    /// Remainder credited to `Node.pending_reward`.
+    pub node_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UsageAttested {
+    pub usage_proof: Pubkey,
+    pub payment_intent: Pubkey,
+    pub oracle: Pubkey,
+    pub attestations: u8,
+    pub quorum: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UsageProofConflicted {
+    pub usage_proof: Pubkey,
+    pub payment_intent: Pubkey,
+    /// Oracle whose attestation disagreed with the recorded proof.
+    pub oracle: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RefundReason {
+    Cancelled,
+    Expired,
+}
+
+#[event]
+pub struct PaymentRefunded {
+    pub payment_intent: Pubkey,
+    pub intent_id: String,
+    pub client: Pubkey,
+    pub amount: u64,
+    pub reason: RefundReason,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub node: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    /// `Node.pending_reward` left after the claim.
+    pub remaining: u64,
+    pub timestamp: i64,
+}
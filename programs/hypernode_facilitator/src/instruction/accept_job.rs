@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::JobAccepted;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptJob<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = operator @ FacilitatorError::NotNodeOperator
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        mut,
+        seeds = [b"intent", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.bump,
+        has_one = client @ FacilitatorError::InvalidClient
+    )]
+    pub payment_intent: Account<'info, PaymentIntent>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ FacilitatorError::ProgramPaused
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    pub operator: Signer<'info>,
+    /// The paying client co-signs, so an operator can only take jobs it was
+    /// handed.
+    pub client: Signer<'info>,
+}
+
+/// Claims an authorized intent for this node. Once assigned, only a usage
+/// proof naming this node can settle it, and the node stays open until the
+/// escrow is paid out or refunded.
+pub fn handler(ctx: Context<AcceptJob>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let node = &mut ctx.accounts.node;
+    require!(node.is_active, FacilitatorError::NodeInactive);
+    require!(!node.jailed, FacilitatorError::NodeJailed);
+    require!(
+        node.is_live(&ctx.accounts.config, clock.slot),
+        FacilitatorError::NodeOffline
+    );
+    require!(
+        node.meets_stake_minimum(&ctx.accounts.config),
+        FacilitatorError::InsufficientNodeStake
+    );
+
+    let intent = &mut ctx.accounts.payment_intent;
+    require!(
+        intent.status == PaymentStatus::Authorized,
+        FacilitatorError::InvalidPaymentStatus
+    );
+    require!(now <= intent.expires_at, FacilitatorError::PaymentExpired);
+    require!(
+        intent.node.is_none(),
+        FacilitatorError::IntentAlreadyAssigned
+    );
+    intent.node = Some(node.key());
+    node.open_job()?;
+
+    emit_cpi!(JobAccepted {
+        payment_intent: intent.key(),
+        node: node.key(),
+        operator: node.operator,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::OwnerTransferred;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptOwnerTransfer<'info> {
+    // Node PDAs are seeded by `node_id`, not by owner, so the address, the
+    // stake vault and all history stay put when the owner changes.
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        constraint = node.pending_owner == Some(new_owner.key()) @ FacilitatorError::NotPendingOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        mut,
+        seeds = [b"operator", node.owner.as_ref()],
+        bump = previous_operator_account.bump
+    )]
+    pub previous_operator_account: Account<'info, OperatorAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = new_owner,
+        space = 8 + OperatorAccount::INIT_SPACE,
+        seeds = [b"operator", new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_operator_account: Account<'info, OperatorAccount>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AcceptOwnerTransfer>) -> Result<()> {
+    let new_owner = ctx.accounts.new_owner.key();
+
+    let previous = &mut ctx.accounts.previous_operator_account;
+    previous.node_count = previous.node_count.saturating_sub(1);
+
+    let next = &mut ctx.accounts.new_operator_account;
+    if next.owner == Pubkey::default() {
+        next.owner = new_owner;
+        next.bump = ctx.bumps.new_operator_account;
+    }
+    next.node_count = next
+        .node_count
+        .checked_add(1)
+        .ok_or(FacilitatorError::MathOverflow)?;
+
+    // The hot key belonged to the previous owner's setup; the new owner
+    // must appoint their own.
+    let node = &mut ctx.accounts.node;
+    let previous_owner = node.owner;
+    node.owner = new_owner;
+    node.operator = new_owner;
+    node.pending_owner = None;
+
+    emit_cpi!(OwnerTransferred {
+        node: node.key(),
+        previous_owner,
+        new_owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::errors::FacilitatorError;
+use crate::events::OracleAdded;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct AddOracle<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + OracleAccount::INIT_SPACE,
+        seeds = [b"oracle", authority.as_ref()],
+        bump
+    )]
+    pub oracle_account: Account<'info, OracleAccount>,
+
+    /// Holds the oracle's HYPER bond, which `slash_oracle` draws on. It can
+    /// be withdrawn one unbonding period after the key is suspended or
+    /// retired.
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"oracle_bond", oracle_account.key().as_ref()],
+        bump,
+        token::mint = hyper_mint,
+        token::authority = bond_vault
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ FacilitatorError::Unauthorized
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(address = config.hyper_mint @ FacilitatorError::InvalidMint)]
+    pub hyper_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Registers an oracle key. It cannot attest until its authority has
+/// bonded at least `oracle_min_bond`.
+pub fn handler(ctx: Context<AddOracle>, authority: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let oracle = &mut ctx.accounts.oracle_account;
+    oracle.authority = authority;
+    oracle.status = OracleStatus::Active;
+    oracle.added_at = now;
+    oracle.retires_at = None;
+    oracle.successor = None;
+    oracle.predecessor = None;
+    oracle.predecessor_retires_at = None;
+    oracle.status_changed_at = now;
+    oracle.bonded_amount = 0;
+    oracle.pending_fees = 0;
+    oracle.bump = ctx.bumps.oracle_account;
+    oracle.bond_vault_bump = ctx.bumps.bond_vault;
+
+    emit_cpi!(OracleAdded {
+        oracle: oracle.key(),
+        authority,
+        timestamp: now,
+    });
+    Ok(())
+}
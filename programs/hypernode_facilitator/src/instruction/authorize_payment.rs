@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::constants::INTENT_ID_MAX_LEN;
+use crate::errors::FacilitatorError;
+use crate::events::PaymentAuthorized;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PaymentIntentData {
+    pub intent_id: String,
+    pub amount: u64,
+    pub expires_at: i64,
+    /// Seconds the client may dispute a verified proof; 0 to settle on proof.
+    pub dispute_window: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(intent_data: PaymentIntentData)]
+pub struct AuthorizePayment<'info> {
+    #[account(
+        init,
+        payer = client,
+        space = 8 + PaymentIntent::INIT_SPACE,
+        seeds = [b"intent", &id_seed(&intent_data.intent_id)],
+        bump
+    )]
+    pub payment_intent: Account<'info, PaymentIntent>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    #[account(
+        init,
+        payer = client,
+        seeds = [b"escrow", &id_seed(&intent_data.intent_id)],
+        bump,
+        token::mint = hyper_mint,
+        token::authority = escrow
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = hyper_mint,
+        token::authority = client
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ FacilitatorError::ProgramPaused
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(address = config.hyper_mint @ FacilitatorError::InvalidMint)]
+    pub hyper_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<AuthorizePayment>, intent_data: PaymentIntentData) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !intent_data.intent_id.is_empty() && intent_data.intent_id.len() <= INTENT_ID_MAX_LEN,
+        FacilitatorError::InvalidIntentId
+    );
+    require!(
+        intent_data.amount > 0,
+        FacilitatorError::InvalidPaymentAmount
+    );
+    require!(
+        intent_data.expires_at > now,
+        FacilitatorError::PaymentExpired
+    );
+    require!(
+        intent_data.expires_at - now <= ctx.accounts.config.max_payment_timeout,
+        FacilitatorError::InvalidExpiry
+    );
+    require!(
+        intent_data.dispute_window >= 0
+            && intent_data.dispute_window <= ctx.accounts.config.max_dispute_window,
+        FacilitatorError::InvalidDisputeWindow
+    );
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.client_token_account.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.client.to_account_info(),
+            },
+        ),
+        intent_data.amount,
+    )?;
+
+    let intent = &mut ctx.accounts.payment_intent;
+    intent.intent_id = intent_data.intent_id;
+    intent.client = ctx.accounts.client.key();
+    intent.amount = intent_data.amount;
+    intent.status = PaymentStatus::Authorized;
+    intent.created_at = now;
+    intent.expires_at = intent_data.expires_at;
+    intent.settled_at = None;
+    intent.node = None;
+    intent.bump = ctx.bumps.payment_intent;
+    intent.escrow_bump = ctx.bumps.escrow;
+    intent.dispute_window = intent_data.dispute_window;
+    intent.verified_at = None;
+    intent.evidence_hash = None;
+    intent.resolution_hash = None;
+    intent.node_lost = false;
+    intent.compensated = 0;
+    intent.slashed = false;
+
+    emit_cpi!(PaymentAuthorized {
+        payment_intent: intent.key(),
+        escrow: ctx.accounts.escrow.key(),
+        intent_id: intent.intent_id.clone(),
+        client: intent.client,
+        amount: intent.amount,
+        expires_at: intent.expires_at,
+        dispute_window: intent.dispute_window,
+        timestamp: now,
+    });
+    Ok(())
+}
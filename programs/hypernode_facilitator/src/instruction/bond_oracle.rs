@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::{OracleBondChangeKind, OracleBondChanged};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BondOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", authority.key().as_ref()],
+        bump = oracle_account.bump
+    )]
+    pub oracle_account: Account<'info, OracleAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"oracle_bond", oracle_account.key().as_ref()],
+        bump = oracle_account.bond_vault_bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ FacilitatorError::ProgramPaused
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(
+        mut,
+        token::mint = bond_vault.mint,
+        token::authority = authority
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<BondOracle>, amount: u64) -> Result<()> {
+    require!(amount > 0, FacilitatorError::InvalidAmount);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                to: ctx.accounts.bond_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let oracle = &mut ctx.accounts.oracle_account;
+    oracle.bonded_amount = oracle
+        .bonded_amount
+        .checked_add(amount)
+        .ok_or(FacilitatorError::MathOverflow)?;
+
+    emit_cpi!(OracleBondChanged {
+        oracle: oracle.key(),
+        authority: oracle.authority,
+        kind: OracleBondChangeKind::Bonded,
+        amount,
+        bonded_amount: oracle.bonded_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
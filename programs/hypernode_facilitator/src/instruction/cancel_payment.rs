@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::{PaymentRefunded, RefundReason};
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelPayment<'info> {
+    #[account(
+        mut,
+        close = client,
+        seeds = [b"intent", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.bump,
+        has_one = client @ FacilitatorError::InvalidClient
+    )]
+    pub payment_intent: Account<'info, PaymentIntent>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.escrow_bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = client
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+
+    /// The node that accepted the intent, if any; its job is released.
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump
+    )]
+    pub node: Option<Account<'info, Node>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CancelPayment>) -> Result<()> {
+    let intent = &ctx.accounts.payment_intent;
+    require!(
+        intent.status == PaymentStatus::Authorized,
+        FacilitatorError::CannotCancel
+    );
+
+    let seeds: &[&[u8]] = &[
+        b"escrow",
+        &id_seed(&intent.intent_id),
+        &[intent.escrow_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow.to_account_info(),
+                to: ctx.accounts.client_token_account.to_account_info(),
+                authority: ctx.accounts.escrow.to_account_info(),
+            },
+            &[seeds],
+        ),
+        // Everything in the escrow, so tokens sent to it on top of the
+        // payment cannot keep it from closing.
+        ctx.accounts.escrow.amount,
+    )?;
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.client.to_account_info(),
+            authority: ctx.accounts.escrow.to_account_info(),
+        },
+        &[seeds],
+    ))?;
+
+    if let Some(assigned) = intent.node {
+        let node = ctx
+            .accounts
+            .node
+            .as_mut()
+            .ok_or(FacilitatorError::MissingNodeAccount)?;
+        require_keys_eq!(node.key(), assigned, FacilitatorError::NodeMismatch);
+        node.close_job();
+    }
+
+    emit_cpi!(PaymentRefunded {
+        payment_intent: intent.key(),
+        intent_id: intent.intent_id.clone(),
+        client: intent.client,
+        amount: intent.amount,
+        reason: RefundReason::Cancelled,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::OracleFeesClaimed;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimOracleFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", authority.key().as_ref()],
+        bump = oracle_account.bump
+    )]
+    pub oracle_account: Account<'info, OracleAccount>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault"],
+        bump = config.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = hyper_mint,
+        associated_token::authority = authority
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    #[account(address = config.hyper_mint @ FacilitatorError::InvalidMint)]
+    pub hyper_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<ClaimOracleFees>, amount: u64) -> Result<()> {
+    require!(amount > 0, FacilitatorError::InvalidAmount);
+
+    let oracle = &mut ctx.accounts.oracle_account;
+    oracle.pending_fees = oracle
+        .pending_fees
+        .checked_sub(amount)
+        .ok_or(FacilitatorError::InsufficientBalance)?;
+
+    let seeds: &[&[u8]] = &[b"reward_vault", &[ctx.accounts.config.reward_vault_bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                to: ctx.accounts.authority_token_account.to_account_info(),
+                authority: ctx.accounts.reward_vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(OracleFeesClaimed {
+        oracle: ctx.accounts.oracle_account.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+        remaining: ctx.accounts.oracle_account.pending_fees,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
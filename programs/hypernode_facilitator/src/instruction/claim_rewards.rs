@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::RewardsClaimed;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = owner @ FacilitatorError::NotNodeOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault"],
+        bump = config.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = hyper_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    #[account(address = config.hyper_mint @ FacilitatorError::InvalidMint)]
+    pub hyper_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<ClaimRewards>, amount: u64) -> Result<()> {
+    require!(amount > 0, FacilitatorError::InvalidAmount);
+
+    let node = &mut ctx.accounts.node;
+    node.pending_reward = node
+        .pending_reward
+        .checked_sub(amount)
+        .ok_or(FacilitatorError::InsufficientBalance)?;
+
+    let seeds: &[&[u8]] = &[b"reward_vault", &[ctx.accounts.config.reward_vault_bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.reward_vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(RewardsClaimed {
+        node: ctx.accounts.node.key(),
+        owner: ctx.accounts.owner.key(),
+        amount,
+        remaining: ctx.accounts.node.pending_reward,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::NodeClosed;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseNode<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = owner @ FacilitatorError::NotNodeOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", node.key().as_ref()],
+        bump = node.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"operator", owner.key().as_ref()],
+        bump = operator_account.bump
+    )]
+    pub operator_account: Account<'info, OperatorAccount>,
+
+    /// Receives any tokens left in the vault that the node did not track.
+    #[account(
+        mut,
+        token::mint = vault.mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Removes a fully drained node and returns the rent of both the node and
+/// its stake vault to the owner. Nodes with assigned jobs stay open so those
+/// escrows can still be settled or refunded against them.
+pub fn handler(ctx: Context<CloseNode>) -> Result<()> {
+    let node = &ctx.accounts.node;
+    require!(
+        node.staked_amount == 0 && node.unbonding_amount == 0 && node.pending_reward == 0,
+        FacilitatorError::NodeNotEmpty
+    );
+    require!(node.open_jobs == 0, FacilitatorError::NodeHasOpenJobs);
+
+    let node_key = node.key();
+    let seeds: &[&[u8]] = &[b"vault", node_key.as_ref(), &[node.vault_bump]];
+    // Untracked tokens, e.g. sent straight to the vault, would otherwise
+    // keep it from closing.
+    let leftover = ctx.accounts.vault.amount;
+    if leftover > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[seeds],
+            ),
+            leftover,
+        )?;
+    }
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        &[seeds],
+    ))?;
+
+    let operator_account = &mut ctx.accounts.operator_account;
+    operator_account.node_count = operator_account.node_count.saturating_sub(1);
+
+    emit_cpi!(NodeClosed {
+        node: node_key,
+        owner: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
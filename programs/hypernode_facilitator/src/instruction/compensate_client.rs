@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::ClientCompensated;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CompensateClient<'info> {
+    #[account(
+        mut,
+        seeds = [b"intent", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.bump
+    )]
+    pub payment_intent: Account<'info, PaymentIntent>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"insurance"],
+        bump = config.insurance_bump
+    )]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = insurance_vault.mint,
+        token::authority = payment_intent.client
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+
+    pub arbiter: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pays a client out of the insurance vault after a dispute the node lost,
+/// for losses the escrow did not cover. Total compensation per intent is capped
+/// at the intent amount.
+pub fn handler(ctx: Context<CompensateClient>, amount: u64) -> Result<()> {
+    require!(amount > 0, FacilitatorError::InvalidAmount);
+    require!(
+        ctx.accounts.config.is_arbiter(ctx.accounts.arbiter.key),
+        FacilitatorError::NotArbiter
+    );
+
+    let intent = &mut ctx.accounts.payment_intent;
+    require!(
+        intent.status == PaymentStatus::Resolved,
+        FacilitatorError::InvalidPaymentStatus
+    );
+    require!(intent.node_lost, FacilitatorError::NodeWonDispute);
+    intent.compensated = intent
+        .compensated
+        .checked_add(amount)
+        .ok_or(FacilitatorError::MathOverflow)?;
+    require!(
+        intent.compensated <= intent.amount,
+        FacilitatorError::CompensationExceeded
+    );
+
+    let seeds: &[&[u8]] = &[b"insurance", &[ctx.accounts.config.insurance_bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.insurance_vault.to_account_info(),
+                to: ctx.accounts.client_token_account.to_account_info(),
+                authority: ctx.accounts.insurance_vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    let intent = &ctx.accounts.payment_intent;
+    emit_cpi!(ClientCompensated {
+        payment_intent: intent.key(),
+        client: intent.client,
+        arbiter: ctx.accounts.arbiter.key(),
+        amount,
+        total_compensated: intent.compensated,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::NodeStatusChanged;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DeactivateNode<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = owner @ FacilitatorError::NotNodeOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Takes the node offline. It keeps its stake and rewards but can no longer
+/// be the target of a settlement.
+pub fn handler(ctx: Context<DeactivateNode>) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+    require!(node.is_active, FacilitatorError::NodeInactive);
+    node.is_active = false;
+
+    emit_cpi!(NodeStatusChanged {
+        node: node.key(),
+        is_active: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::constants::REPUTATION_JOB_COMPLETED;
+use crate::errors::FacilitatorError;
+use crate::events::{PaymentSettled, ReputationReason, ReputationUpdated};
+use crate::state::*;
+use crate::utils::{credit_oracle_fees, id_seed, release_escrow, SettlementSplit};
+
+/// Releases a verified escrow once its dispute window has passed. Anyone
+/// may crank it. The attestors' `OracleAccount`s must be passed as writable
+/// remaining accounts so each is credited its share of the oracle fee; the
+/// share of an attestor removed since goes to the treasury.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeSettlement<'info> {
+    #[account(
+        mut,
+        seeds = [b"intent", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.bump
+    )]
+    pub payment_intent: Account<'info, PaymentIntent>,
+
+    #[account(
+        seeds = [b"proof", &id_seed(&payment_intent.intent_id)],
+        bump = usage_proof.bump
+    )]
+    pub usage_proof: Account<'info, UsageProof>,
+
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        constraint = payment_intent.node == Some(node.key()) @ FacilitatorError::NodeMismatch
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ FacilitatorError::ProgramPaused
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.escrow_bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault"],
+        bump = config.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = config.treasury_bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"insurance"],
+        bump = config.insurance_bump
+    )]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: receives the escrow account's rent; must be the paying client.
+    #[account(mut, address = payment_intent.client @ FacilitatorError::InvalidClient)]
+    pub client: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeSettlement<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let intent = &ctx.accounts.payment_intent;
+    require!(
+        intent.status == PaymentStatus::Verified,
+        FacilitatorError::InvalidPaymentStatus
+    );
+    let dispute_ends_at = intent
+        .dispute_ends_at()
+        .ok_or(FacilitatorError::MathOverflow)?;
+    require!(now >= dispute_ends_at, FacilitatorError::DisputeWindowOpen);
+
+    let proof = &ctx.accounts.usage_proof;
+    let amount = intent.amount;
+    let uptime_bps = ctx
+        .accounts
+        .node
+        .uptime_bps(&ctx.accounts.config, clock.slot);
+    let mut split = SettlementSplit::new(
+        &ctx.accounts.config,
+        amount,
+        proof.attestors.len(),
+        ctx.accounts.treasury.amount,
+    )?;
+    let forfeited = credit_oracle_fees(
+        &proof.attestors,
+        split.oracle_share,
+        None,
+        ctx.remaining_accounts,
+    )?;
+    split.forfeit_oracle_fees(forfeited);
+    split.withhold_for_downtime(&ctx.accounts.config, uptime_bps)?;
+    release_escrow(
+        intent,
+        &ctx.accounts.escrow,
+        &[
+            (
+                ctx.accounts.treasury.to_account_info(),
+                split.fee - split.insurance_fee,
+            ),
+            (
+                ctx.accounts.insurance_vault.to_account_info(),
+                split.insurance_fee + split.uptime_withheld,
+            ),
+            (
+                ctx.accounts.reward_vault.to_account_info(),
+                split.node_amount + split.oracle_fee,
+            ),
+        ],
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.client.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    )?;
+    let node = &mut ctx.accounts.node;
+    node.record_settlement(split.node_amount, now)?;
+    node.close_job();
+
+    let intent = &mut ctx.accounts.payment_intent;
+    intent.status = PaymentStatus::Completed;
+    intent.settled_at = Some(now);
+
+    emit_cpi!(PaymentSettled {
+        payment_intent: intent.key(),
+        usage_proof: proof.key(),
+        intent_id: intent.intent_id.clone(),
+        node: ctx.accounts.node.key(),
+        attestors: proof.attestors.clone(),
+        amount,
+        fee: split.fee,
+        insurance_fee: split.insurance_fee,
+        oracle_fee: split.oracle_fee,
+        uptime_withheld: split.uptime_withheld,
+        node_amount: split.node_amount,
+        timestamp: now,
+    });
+    emit_cpi!(ReputationUpdated {
+        node: ctx.accounts.node.key(),
+        reason: ReputationReason::JobCompleted,
+        delta: REPUTATION_JOB_COMPLETED,
+        reputation: ctx.accounts.node.reputation,
+        timestamp: now,
+    });
+    Ok(())
+}
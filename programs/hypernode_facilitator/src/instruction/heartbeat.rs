@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::REPUTATION_UPTIME;
+use crate::errors::FacilitatorError;
+use crate::events::{ReputationReason, ReputationUpdated};
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = operator @ FacilitatorError::NotNodeOperator
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    pub operator: Signer<'info>,
+}
+
+/// Liveness signal from the node's operator key. The first heartbeat in
+/// each epoch counts towards uptime and reputation; later ones only move
+/// `last_heartbeat_slot`.
+pub fn handler(ctx: Context<Heartbeat>) -> Result<()> {
+    let clock = Clock::get()?;
+    let epoch_slots = ctx.accounts.config.heartbeat_epoch_slots;
+    let node = &mut ctx.accounts.node;
+    let first_in_epoch = node.last_heartbeat_slot / epoch_slots < clock.slot / epoch_slots;
+    node.last_heartbeat_slot = clock.slot;
+    if !first_in_epoch {
+        return Ok(());
+    }
+
+    node.uptime_epochs = node
+        .uptime_epochs
+        .checked_add(1)
+        .ok_or(FacilitatorError::MathOverflow)?;
+    node.adjust_reputation(REPUTATION_UPTIME, clock.unix_timestamp);
+
+    emit_cpi!(ReputationUpdated {
+        node: node.key(),
+        reason: ReputationReason::Uptime,
+        delta: REPUTATION_UPTIME,
+        reputation: node.reputation,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::errors::FacilitatorError;
+use crate::program::HypernodeFacilitator;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + FacilitatorConfig::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    /// Pool that settled payments are paid into until nodes claim them.
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"reward_vault"],
+        bump,
+        token::mint = hyper_mint,
+        token::authority = reward_vault
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// Receives the protocol fee taken from every settlement, up to
+    /// `insurance_fee_threshold`.
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"treasury"],
+        bump,
+        token::mint = hyper_mint,
+        token::authority = treasury
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// Receives slashed stake and protocol fees above the treasury
+    /// threshold; pays out `compensate_client`.
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"insurance"],
+        bump,
+        token::mint = hyper_mint,
+        token::authority = insurance_vault
+    )]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub hyper_mint: Account<'info, Mint>,
+
+    /// Only the upgrade authority may create the config, so it cannot be
+    /// front-run right after deployment.
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, HypernodeFacilitator>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ FacilitatorError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.hyper_mint = ctx.accounts.hyper_mint.key();
+    config.apply(params)?;
+    config.bump = ctx.bumps.config;
+    config.reward_vault_bump = ctx.bumps.reward_vault;
+    config.treasury_bump = ctx.bumps.treasury;
+    config.insurance_bump = ctx.bumps.insurance_vault;
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::NodeStatusChanged;
+use crate::state::*;
+use crate::utils::id_seed;
+
+/// Permissionless crank: deactivates a node that has gone
+/// `max_missed_heartbeats` epochs without a heartbeat.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MarkNodeOffline<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+}
+
+pub fn handler(ctx: Context<MarkNodeOffline>) -> Result<()> {
+    let clock = Clock::get()?;
+    let node = &mut ctx.accounts.node;
+    require!(node.is_active, FacilitatorError::NodeInactive);
+    require!(
+        !node.is_live(&ctx.accounts.config, clock.slot),
+        FacilitatorError::HeartbeatCurrent
+    );
+    node.is_active = false;
+
+    emit_cpi!(NodeStatusChanged {
+        node: node.key(),
+        is_active: false,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,71 @@
+pub mod accept_job;
+pub mod accept_owner_transfer;
+pub mod add_oracle;
+pub mod authorize_payment;
+pub mod bond_oracle;
+pub mod cancel_payment;
+pub mod claim_oracle_fees;
+pub mod claim_rewards;
+pub mod close_node;
+pub mod compensate_client;
+pub mod deactivate_node;
+pub mod finalize_settlement;
+pub mod heartbeat;
+pub mod initialize_config;
+pub mod mark_node_offline;
+pub mod open_dispute;
+pub mod propose_owner_transfer;
+pub mod reactivate_node;
+pub mod refund_expired;
+pub mod register_node;
+pub mod remove_oracle;
+pub mod request_unstake;
+pub mod resolve_dispute;
+pub mod rotate_oracle;
+pub mod set_operator;
+pub mod set_oracle_status;
+pub mod slash_node;
+pub mod slash_oracle;
+pub mod stake;
+pub mod submit_usage_proof;
+pub mod unjail;
+pub mod update_config;
+pub mod update_node_profile;
+pub mod withdraw_oracle_bond;
+pub mod withdraw_unstaked;
+
+pub use accept_job::*;
+pub use accept_owner_transfer::*;
+pub use add_oracle::*;
+pub use authorize_payment::*;
+pub use bond_oracle::*;
+pub use cancel_payment::*;
+pub use claim_oracle_fees::*;
+pub use claim_rewards::*;
+pub use close_node::*;
+pub use compensate_client::*;
+pub use deactivate_node::*;
+pub use finalize_settlement::*;
+pub use heartbeat::*;
+pub use initialize_config::*;
+pub use mark_node_offline::*;
+pub use open_dispute::*;
+pub use propose_owner_transfer::*;
+pub use reactivate_node::*;
+pub use refund_expired::*;
+pub use register_node::*;
+pub use remove_oracle::*;
+pub use request_unstake::*;
+pub use resolve_dispute::*;
+pub use rotate_oracle::*;
+pub use set_operator::*;
+pub use set_oracle_status::*;
+pub use slash_node::*;
+pub use slash_oracle::*;
+pub use stake::*;
+pub use submit_usage_proof::*;
+pub use unjail::*;
+pub use update_config::*;
+pub use update_node_profile::*;
+pub use withdraw_oracle_bond::*;
+pub use withdraw_unstaked::*;
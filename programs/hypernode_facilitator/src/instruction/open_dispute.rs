@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::DisputeOpened;
+use crate::state::*;
+use crate::utils::{id_seed, parse_hash_hex};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"intent", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.bump,
+        has_one = client @ FacilitatorError::InvalidClient
+    )]
+    pub payment_intent: Account<'info, PaymentIntent>,
+
+    pub client: Signer<'info>,
+}
+
+/// Challenges a verified proof while its dispute window is open. The escrow
+/// stays frozen until the dispute is resolved.
+pub fn handler(ctx: Context<OpenDispute>, evidence_hash: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let evidence_hash =
+        parse_hash_hex(&evidence_hash).ok_or(FacilitatorError::InvalidEvidenceHash)?;
+
+    let intent = &mut ctx.accounts.payment_intent;
+    require!(
+        intent.status == PaymentStatus::Verified,
+        FacilitatorError::InvalidPaymentStatus
+    );
+    let dispute_ends_at = intent
+        .dispute_ends_at()
+        .ok_or(FacilitatorError::MathOverflow)?;
+    require!(now < dispute_ends_at, FacilitatorError::DisputeWindowClosed);
+
+    intent.status = PaymentStatus::Disputed;
+    intent.evidence_hash = Some(evidence_hash);
+
+    emit_cpi!(DisputeOpened {
+        payment_intent: intent.key(),
+        client: intent.client,
+        node: intent.node,
+        evidence_hash,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::OwnerTransferProposed;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeOwnerTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = owner @ FacilitatorError::NotNodeOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    pub owner: Signer<'info>,
+}
+
+/// First step of an ownership transfer. Passing `None` withdraws a pending
+/// proposal.
+pub fn handler(ctx: Context<ProposeOwnerTransfer>, new_owner: Option<Pubkey>) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+    require!(
+        new_owner != Some(node.owner),
+        FacilitatorError::InvalidNewOwner
+    );
+    node.pending_owner = new_owner;
+
+    emit_cpi!(OwnerTransferProposed {
+        node: node.key(),
+        owner: node.owner,
+        pending_owner: new_owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
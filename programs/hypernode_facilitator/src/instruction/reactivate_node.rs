@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::NodeStatusChanged;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReactivateNode<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = owner @ FacilitatorError::NotNodeOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ FacilitatorError::ProgramPaused
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Brings a node back online. Its operator has to have sent a recent
+/// heartbeat, or the offline crank would switch it straight off again.
+pub fn handler(ctx: Context<ReactivateNode>) -> Result<()> {
+    let clock = Clock::get()?;
+    let node = &mut ctx.accounts.node;
+    require!(!node.is_active, FacilitatorError::NodeAlreadyActive);
+    require!(
+        node.meets_stake_minimum(&ctx.accounts.config),
+        FacilitatorError::InsufficientNodeStake
+    );
+    require!(
+        node.is_live(&ctx.accounts.config, clock.slot),
+        FacilitatorError::NodeOffline
+    );
+    node.is_active = true;
+
+    emit_cpi!(NodeStatusChanged {
+        node: node.key(),
+        is_active: true,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
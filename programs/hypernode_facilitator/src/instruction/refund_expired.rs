@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::{NodeJailed, PaymentRefunded, RefundReason};
+use crate::state::*;
+use crate::utils::id_seed;
+
+/// Permissionless crank: once an authorized intent passes `expires_at`
+/// without a usage proof, anyone may return the escrow to the client. If a
+/// node had accepted the job, its account must be passed so it is charged a
+/// failure; it cannot have been closed while the job was assigned.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundExpired<'info> {
+    #[account(
+        mut,
+        close = client,
+        seeds = [b"intent", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.bump,
+        has_one = client @ FacilitatorError::InvalidClient
+    )]
+    pub payment_intent: Account<'info, PaymentIntent>,
+
+    /// CHECK: refund and rent destination; pinned by `has_one` above.
+    #[account(mut)]
+    pub client: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.escrow_bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = client
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+
+    /// The node that accepted the intent, if any.
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump
+    )]
+    pub node: Option<Account<'info, Node>>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RefundExpired>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let intent = &ctx.accounts.payment_intent;
+    require!(
+        intent.status == PaymentStatus::Authorized,
+        FacilitatorError::InvalidPaymentStatus
+    );
+    require!(now > intent.expires_at, FacilitatorError::PaymentNotExpired);
+
+    let seeds: &[&[u8]] = &[
+        b"escrow",
+        &id_seed(&intent.intent_id),
+        &[intent.escrow_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow.to_account_info(),
+                to: ctx.accounts.client_token_account.to_account_info(),
+                authority: ctx.accounts.escrow.to_account_info(),
+            },
+            &[seeds],
+        ),
+        // The whole balance: a non-empty escrow could not be closed.
+        ctx.accounts.escrow.amount,
+    )?;
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.client.to_account_info(),
+            authority: ctx.accounts.escrow.to_account_info(),
+        },
+        &[seeds],
+    ))?;
+
+    emit_cpi!(PaymentRefunded {
+        payment_intent: intent.key(),
+        intent_id: intent.intent_id.clone(),
+        client: intent.client,
+        amount: intent.amount,
+        reason: RefundReason::Expired,
+        timestamp: now,
+    });
+
+    if let Some(assigned) = intent.node {
+        let node = ctx
+            .accounts
+            .node
+            .as_mut()
+            .ok_or(FacilitatorError::MissingNodeAccount)?;
+        require_keys_eq!(node.key(), assigned, FacilitatorError::NodeMismatch);
+        node.close_job();
+        if node.record_failure(&ctx.accounts.config, now) {
+            emit_cpi!(NodeJailed {
+                node: assigned,
+                owner: node.owner,
+                consecutive_failures: node.consecutive_failures,
+                timestamp: now,
+            });
+        }
+    }
+    Ok(())
+}
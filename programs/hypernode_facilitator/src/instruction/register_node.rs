@@ -1,28 +1,112 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::constants::REPUTATION_BASELINE;
+use crate::errors::FacilitatorError;
+use crate::events::NodeRegistered;
 use crate::state::*;
+use crate::utils::id_seed;
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(profile: NodeProfile)]
 pub struct RegisterNode<'info> {
     #[account(
         init,
         payer = user,
-        space = 8 + 32 + 8 + 8 + 1,
-        seeds = [b"node", user.key().as_ref()],
+        space = 8 + Node::INIT_SPACE,
+        seeds = [b"node", &id_seed(&profile.node_id)],
         bump
     )]
     pub node: Account<'info, Node>,
 
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + OperatorAccount::INIT_SPACE,
+        seeds = [b"operator", user.key().as_ref()],
+        bump
+    )]
+    pub operator_account: Account<'info, OperatorAccount>,
+
+    /// Holds the node's staked HYPER. Owned by its own PDA so only the
+    /// program can move funds out of it.
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"vault", node.key().as_ref()],
+        bump,
+        token::mint = hyper_mint,
+        token::authority = vault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ FacilitatorError::ProgramPaused
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(address = config.hyper_mint @ FacilitatorError::InvalidMint)]
+    pub hyper_mint: Account<'info, Mint>,
+
     pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<RegisterNode>) -> Result<()> {
+pub fn handler(ctx: Context<RegisterNode>, profile: NodeProfile) -> Result<()> {
+    profile.validate()?;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
     let node = &mut ctx.accounts.node;
     node.owner = *ctx.accounts.user.key;
     node.staked_amount = 0;
     node.pending_reward = 0;
-    node.bump = *ctx.bumps.get("node").unwrap();
+    node.bump = ctx.bumps.node;
+    node.vault_bump = ctx.bumps.vault;
+    node.node_id = profile.node_id.clone();
+    node.set_profile(profile);
+    node.total_earned = 0;
+    node.jobs_completed = 0;
+    node.is_active = true;
+    node.registered_at = now;
+    node.registered_slot = clock.slot;
+    node.operator = node.owner;
+    node.pending_owner = None;
+    node.slash_count = 0;
+    node.total_slashed = 0;
+    node.last_slashed_at = 0;
+    node.consecutive_failures = 0;
+    node.jailed = false;
+    node.jailed_at = 0;
+    node.reputation = REPUTATION_BASELINE;
+    node.reputation_updated_at = now;
+    node.reputation_fixed = u64::from(REPUTATION_BASELINE) << 32;
+    // Registration counts as the first sign of life.
+    node.last_heartbeat_slot = clock.slot;
+    node.uptime_epochs = 1;
+    node.open_jobs = 0;
+
+    let operator_account = &mut ctx.accounts.operator_account;
+    if operator_account.owner == Pubkey::default() {
+        operator_account.owner = node.owner;
+        operator_account.bump = ctx.bumps.operator_account;
+    }
+    operator_account.node_count = operator_account
+        .node_count
+        .checked_add(1)
+        .ok_or(FacilitatorError::MathOverflow)?;
+
+    emit_cpi!(NodeRegistered {
+        node: node.key(),
+        node_id: node.node_id.clone(),
+        owner: node.owner,
+        vault: ctx.accounts.vault.key(),
+        timestamp: now,
+    });
     Ok(())
 }
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::OracleRemoved;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveOracle<'info> {
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"oracle", oracle_account.authority.as_ref()],
+        bump = oracle_account.bump
+    )]
+    pub oracle_account: Account<'info, OracleAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"oracle_bond", oracle_account.key().as_ref()],
+        bump = oracle_account.bond_vault_bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ FacilitatorError::Unauthorized
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    /// Takes whatever the bond vault holds beyond the tracked bond.
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = config.treasury_bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Deletes an oracle entry once its bond has been withdrawn or slashed
+/// away and its fees claimed. Tokens sent to the bond vault outside
+/// `bond_oracle` are not the oracle's and go to the treasury.
+pub fn handler(ctx: Context<RemoveOracle>) -> Result<()> {
+    let oracle = &ctx.accounts.oracle_account;
+    require!(
+        oracle.bonded_amount == 0 && oracle.pending_fees == 0,
+        FacilitatorError::OracleNotEmpty
+    );
+
+    let oracle_key = oracle.key();
+    let seeds: &[&[u8]] = &[
+        b"oracle_bond",
+        oracle_key.as_ref(),
+        &[oracle.bond_vault_bump],
+    ];
+    let leftover = ctx.accounts.bond_vault.amount;
+    if leftover > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.bond_vault.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                    authority: ctx.accounts.bond_vault.to_account_info(),
+                },
+                &[seeds],
+            ),
+            leftover,
+        )?;
+    }
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.bond_vault.to_account_info(),
+            destination: ctx.accounts.admin.to_account_info(),
+            authority: ctx.accounts.bond_vault.to_account_info(),
+        },
+        &[seeds],
+    ))?;
+
+    emit_cpi!(OracleRemoved {
+        oracle: ctx.accounts.oracle_account.key(),
+        authority: ctx.accounts.oracle_account.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
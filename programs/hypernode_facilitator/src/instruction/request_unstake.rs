@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::{StakeChangeKind, StakeChanged};
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = owner @ FacilitatorError::NotNodeOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Moves `amount` from active stake into the unbonding queue. A new request
+/// restarts the cooldown for everything already queued, so stake can never
+/// leave earlier than `unbonding_period` after the latest request.
+pub fn handler(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+    require!(amount > 0, FacilitatorError::InvalidAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    let node = &mut ctx.accounts.node;
+    require!(
+        amount <= node.staked_amount,
+        FacilitatorError::InsufficientStake
+    );
+
+    node.staked_amount -= amount;
+    node.unbonding_amount = node
+        .unbonding_amount
+        .checked_add(amount)
+        .ok_or(FacilitatorError::MathOverflow)?;
+    node.unbonding_ready_at = now
+        .checked_add(ctx.accounts.config.unbonding_period)
+        .ok_or(FacilitatorError::MathOverflow)?;
+
+    emit_cpi!(StakeChanged {
+        node: node.key(),
+        owner: node.owner,
+        kind: StakeChangeKind::UnstakeRequested,
+        amount,
+        staked_amount: node.staked_amount,
+        unbonding_amount: node.unbonding_amount,
+        timestamp: now,
+    });
+    Ok(())
+}
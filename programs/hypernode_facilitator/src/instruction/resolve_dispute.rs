@@ -0,0 +1,283 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::constants::{BPS_DENOMINATOR, REPUTATION_DISPUTE_LOST, REPUTATION_SLASHED};
+use crate::errors::FacilitatorError;
+use crate::events::{
+    DisputeResolved, NodeJailed, NodeSlashed, NodeStatusChanged, ReputationReason,
+    ReputationUpdated,
+};
+use crate::state::*;
+use crate::utils::{
+    bps_of, id_seed, insure_slashed_stake, parse_hash_hex, release_escrow, SettlementSplit,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DisputeResolution {
+    /// Share of the escrow refunded to the client; the rest goes to the node.
+    pub client_bps: u16,
+    /// HYPER slashed from the node's stake into the insurance vault.
+    pub slash_amount: u64,
+    pub resolution_hash: String, // SHA256 hex
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"intent", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.bump
+    )]
+    pub payment_intent: Account<'info, PaymentIntent>,
+
+    #[account(
+        seeds = [b"proof", &id_seed(&payment_intent.intent_id)],
+        bump = usage_proof.bump
+    )]
+    pub usage_proof: Account<'info, UsageProof>,
+
+    /// The node the escrow was released to, or the one named in the proof
+    /// if the oracles never agreed. An assigned node cannot be closed while
+    /// the dispute is open; an unassigned one may already be gone, in which
+    /// case it is left out and the escrow can only go back to the client.
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        constraint = node.key() == payment_intent.node.unwrap_or(usage_proof.node)
+            @ FacilitatorError::NodeMismatch
+    )]
+    pub node: Option<Account<'info, Node>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", node.key().as_ref()],
+        bump = node.vault_bump
+    )]
+    pub vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.escrow_bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault"],
+        bump = config.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = config.treasury_bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = client
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: receives the escrow account's rent; must be the paying client.
+    #[account(mut, address = payment_intent.client @ FacilitatorError::InvalidClient)]
+    pub client: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"insurance"],
+        bump = config.insurance_bump
+    )]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    pub arbiter: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Settles a disputed escrow on the arbiters' decision: splits it between
+/// client and node, optionally slashes the node, and records the decision.
+/// The node's share pays the protocol fee like any other settlement.
+pub fn handler(ctx: Context<ResolveDispute>, resolution: DisputeResolution) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.config.is_arbiter(ctx.accounts.arbiter.key),
+        FacilitatorError::NotArbiter
+    );
+    require!(
+        u64::from(resolution.client_bps) <= BPS_DENOMINATOR,
+        FacilitatorError::InvalidSplit
+    );
+    let resolution_hash = parse_hash_hex(&resolution.resolution_hash)
+        .ok_or(FacilitatorError::InvalidResolutionHash)?;
+
+    let intent = &ctx.accounts.payment_intent;
+    require!(
+        intent.status == PaymentStatus::Disputed,
+        FacilitatorError::InvalidPaymentStatus
+    );
+    // A conflicted intent may already have backed a `slash_node`.
+    require!(
+        resolution.slash_amount == 0 || !intent.slashed,
+        FacilitatorError::SlashEvidenceUsed
+    );
+
+    let amount = intent.amount;
+    let client_amount = bps_of(amount, resolution.client_bps)?;
+    let split = SettlementSplit::new(
+        &ctx.accounts.config,
+        amount - client_amount,
+        0,
+        ctx.accounts.treasury.amount,
+    )?;
+    let node_amount = split.node_amount;
+    if ctx.accounts.node.is_none() || ctx.accounts.vault.is_none() {
+        require!(
+            intent.node.is_none() && client_amount == amount && resolution.slash_amount == 0,
+            FacilitatorError::MissingNodeAccount
+        );
+    }
+    let was_assigned = intent.node.is_some();
+    release_escrow(
+        intent,
+        &ctx.accounts.escrow,
+        &[
+            (
+                ctx.accounts.client_token_account.to_account_info(),
+                client_amount,
+            ),
+            (
+                ctx.accounts.treasury.to_account_info(),
+                split.fee - split.insurance_fee,
+            ),
+            (
+                ctx.accounts.insurance_vault.to_account_info(),
+                split.insurance_fee,
+            ),
+            (ctx.accounts.reward_vault.to_account_info(), node_amount),
+        ],
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.client.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    // A node that loses most of the escrow has lost the dispute and is
+    // charged a failed job.
+    let lost = u64::from(resolution.client_bps) * 2 > BPS_DENOMINATOR;
+    let intent = &mut ctx.accounts.payment_intent;
+    intent.status = PaymentStatus::Resolved;
+    intent.settled_at = Some(now);
+    intent.resolution_hash = Some(resolution_hash);
+    intent.node_lost = lost;
+
+    let (Some(node), Some(vault)) = (ctx.accounts.node.as_mut(), ctx.accounts.vault.as_ref())
+    else {
+        emit_cpi!(DisputeResolved {
+            payment_intent: intent.key(),
+            arbiter: ctx.accounts.arbiter.key(),
+            node: ctx.accounts.usage_proof.node,
+            client_amount,
+            fee: split.fee,
+            node_amount,
+            slashed: 0,
+            resolution_hash,
+            timestamp: now,
+        });
+        return Ok(());
+    };
+    intent.node = Some(node.key());
+    if was_assigned {
+        node.close_job();
+    }
+    node.pending_reward = node
+        .pending_reward
+        .checked_add(node_amount)
+        .ok_or(FacilitatorError::MathOverflow)?;
+    node.total_earned = node
+        .total_earned
+        .checked_add(node_amount)
+        .ok_or(FacilitatorError::MathOverflow)?;
+
+    let jailed = lost && node.record_failure(&ctx.accounts.config, now);
+    if lost {
+        node.adjust_reputation(REPUTATION_DISPUTE_LOST, now);
+    }
+    let reputation_after_dispute = node.reputation;
+    let slashed = node.slash(resolution.slash_amount, now)?;
+    intent.slashed |= slashed > 0;
+    let deactivated = node.deactivate_if_understaked(&ctx.accounts.config);
+    if slashed > 0 {
+        insure_slashed_stake(
+            node,
+            vault,
+            &ctx.accounts.insurance_vault,
+            ctx.accounts.token_program.to_account_info(),
+            slashed,
+        )?;
+    }
+
+    emit_cpi!(DisputeResolved {
+        payment_intent: intent.key(),
+        arbiter: ctx.accounts.arbiter.key(),
+        node: node.key(),
+        client_amount,
+        fee: split.fee,
+        node_amount,
+        slashed,
+        resolution_hash,
+        timestamp: now,
+    });
+    if lost {
+        emit_cpi!(ReputationUpdated {
+            node: node.key(),
+            reason: ReputationReason::DisputeLost,
+            delta: REPUTATION_DISPUTE_LOST,
+            reputation: reputation_after_dispute,
+            timestamp: now,
+        });
+    }
+    if slashed > 0 {
+        emit_cpi!(NodeSlashed {
+            node: node.key(),
+            owner: node.owner,
+            payment_intent: intent.key(),
+            slasher: ctx.accounts.arbiter.key(),
+            amount: slashed,
+            staked_amount: node.staked_amount,
+            unbonding_amount: node.unbonding_amount,
+            slash_count: node.slash_count,
+            evidence_hash: resolution_hash,
+            timestamp: now,
+        });
+        emit_cpi!(ReputationUpdated {
+            node: node.key(),
+            reason: ReputationReason::Slashed,
+            delta: REPUTATION_SLASHED,
+            reputation: node.reputation,
+            timestamp: now,
+        });
+    }
+    if deactivated {
+        emit_cpi!(NodeStatusChanged {
+            node: node.key(),
+            is_active: false,
+            timestamp: now,
+        });
+    }
+    if jailed {
+        emit_cpi!(NodeJailed {
+            node: node.key(),
+            owner: node.owner,
+            consecutive_failures: node.consecutive_failures,
+            timestamp: now,
+        });
+    }
+    Ok(())
+}
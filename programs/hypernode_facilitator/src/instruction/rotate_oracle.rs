@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::errors::FacilitatorError;
+use crate::events::OracleRotated;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(new_authority: Pubkey)]
+pub struct RotateOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle_account.authority.as_ref()],
+        bump = oracle_account.bump
+    )]
+    pub oracle_account: Account<'info, OracleAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + OracleAccount::INIT_SPACE,
+        seeds = [b"oracle", new_authority.as_ref()],
+        bump
+    )]
+    pub new_oracle_account: Account<'info, OracleAccount>,
+
+    /// Bond vault for the new key, which starts unbonded.
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"oracle_bond", new_oracle_account.key().as_ref()],
+        bump,
+        token::mint = hyper_mint,
+        token::authority = new_bond_vault
+    )]
+    pub new_bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ FacilitatorError::Unauthorized
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(address = config.hyper_mint @ FacilitatorError::InvalidMint)]
+    pub hyper_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Replaces an oracle key with `new_authority`. Both keys are accepted for
+/// `oracle_rotation_overlap` seconds so the oracle service can switch over
+/// without missing proofs. Bonds do not move with the key: the new key
+/// bonds on its own, and the old bond unlocks one unbonding period after
+/// the old key retires. A key cannot be rotated again until its own
+/// predecessor has retired.
+pub fn handler(ctx: Context<RotateOracle>, new_authority: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let retires_at = now
+        .checked_add(ctx.accounts.config.oracle_rotation_overlap)
+        .ok_or(FacilitatorError::MathOverflow)?;
+
+    let previous = &mut ctx.accounts.oracle_account;
+    require!(
+        previous.retires_at.is_none(),
+        FacilitatorError::OracleRetired
+    );
+    require!(
+        previous.predecessor_retires_at.map_or(true, |at| now >= at),
+        FacilitatorError::RotationInProgress
+    );
+    previous.retires_at = Some(retires_at);
+    previous.successor = Some(new_authority);
+
+    let next = &mut ctx.accounts.new_oracle_account;
+    next.authority = new_authority;
+    next.status = previous.status;
+    next.added_at = now;
+    next.retires_at = None;
+    next.successor = None;
+    next.predecessor = Some(previous.authority);
+    next.predecessor_retires_at = Some(retires_at);
+    next.status_changed_at = now;
+    next.bonded_amount = 0;
+    next.pending_fees = 0;
+    next.bump = ctx.bumps.new_oracle_account;
+    next.bond_vault_bump = ctx.bumps.new_bond_vault;
+
+    emit_cpi!(OracleRotated {
+        previous_authority: previous.authority,
+        new_authority,
+        retires_at,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::OperatorChanged;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetOperator<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = owner @ FacilitatorError::NotNodeOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetOperator>, operator: Pubkey) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+    node.operator = operator;
+
+    emit_cpi!(OperatorChanged {
+        node: node.key(),
+        operator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
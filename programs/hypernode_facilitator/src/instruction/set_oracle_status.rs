@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::OracleStatusChanged;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetOracleStatus<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle_account.authority.as_ref()],
+        bump = oracle_account.bump
+    )]
+    pub oracle_account: Account<'info, OracleAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ FacilitatorError::Unauthorized
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Suspends or reinstates an oracle without losing its registry entry.
+pub fn handler(ctx: Context<SetOracleStatus>, status: OracleStatus) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let oracle = &mut ctx.accounts.oracle_account;
+    if oracle.status != status {
+        oracle.status = status;
+        oracle.status_changed_at = now;
+    }
+
+    emit_cpi!(OracleStatusChanged {
+        oracle: oracle.key(),
+        authority: oracle.authority,
+        status,
+        timestamp: now,
+    });
+    Ok(())
+}
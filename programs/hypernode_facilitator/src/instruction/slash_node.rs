@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::constants::REPUTATION_SLASHED;
+use crate::errors::FacilitatorError;
+use crate::events::{NodeSlashed, NodeStatusChanged, ReputationReason, ReputationUpdated};
+use crate::state::*;
+use crate::utils::{bps_of, count_oracle_signers, id_seed, insure_slashed_stake, parse_hash_hex};
+
+/// Moves `slash_bps` of a node's stake, unbonding funds included, into the
+/// insurance vault for proven misbehaviour. Either an arbiter signs, or
+/// `oracle_quorum` oracles co-sign by passing `[oracle_account, authority]`
+/// pairs as remaining accounts.
+///
+/// The slash must rest on an intent that the arbiters resolved or whose
+/// usage proof the oracles disagreed on, and each intent backs at most one
+/// slash.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SlashNode<'info> {
+    #[account(
+        mut,
+        seeds = [b"intent", &id_seed(&payment_intent.intent_id)],
+        bump = payment_intent.bump
+    )]
+    pub payment_intent: Account<'info, PaymentIntent>,
+
+    #[account(
+        seeds = [b"proof", &id_seed(&payment_intent.intent_id)],
+        bump = usage_proof.bump
+    )]
+    pub usage_proof: Account<'info, UsageProof>,
+
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        constraint = node.key() == payment_intent.node.unwrap_or(usage_proof.node)
+            @ FacilitatorError::NodeMismatch
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", node.key().as_ref()],
+        bump = node.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"insurance"],
+        bump = config.insurance_bump
+    )]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    pub slasher: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SlashNode<'info>>,
+    evidence_hash: String,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let evidence_hash =
+        parse_hash_hex(&evidence_hash).ok_or(FacilitatorError::InvalidEvidenceHash)?;
+
+    let intent = &ctx.accounts.payment_intent;
+    let proof = &ctx.accounts.usage_proof;
+    let conflicted =
+        proof.status == ProofStatus::Conflicted && proof.intent_created_at == intent.created_at;
+    require!(
+        intent.status == PaymentStatus::Resolved || conflicted,
+        FacilitatorError::InvalidPaymentStatus
+    );
+    require!(!intent.slashed, FacilitatorError::SlashEvidenceUsed);
+
+    let config = &ctx.accounts.config;
+    if !config.is_arbiter(ctx.accounts.slasher.key) {
+        let signers = count_oracle_signers(config, ctx.remaining_accounts, now)?;
+        require!(
+            signers >= usize::from(config.oracle_quorum),
+            FacilitatorError::Unauthorized
+        );
+    }
+
+    let node = &mut ctx.accounts.node;
+    // Unbonding stake is still at risk, so requesting an unstake does not
+    // shrink the penalty.
+    let slashable = node
+        .staked_amount
+        .checked_add(node.unbonding_amount)
+        .ok_or(FacilitatorError::MathOverflow)?;
+    let amount = bps_of(slashable, config.slash_bps)?;
+    let slashed = node.slash(amount, now)?;
+    require!(slashed > 0, FacilitatorError::NothingToSlash);
+    ctx.accounts.payment_intent.slashed = true;
+    let deactivated = node.deactivate_if_understaked(config);
+
+    insure_slashed_stake(
+        &ctx.accounts.node,
+        &ctx.accounts.vault,
+        &ctx.accounts.insurance_vault,
+        ctx.accounts.token_program.to_account_info(),
+        slashed,
+    )?;
+
+    let node = &ctx.accounts.node;
+    emit_cpi!(NodeSlashed {
+        node: node.key(),
+        owner: node.owner,
+        payment_intent: ctx.accounts.payment_intent.key(),
+        slasher: ctx.accounts.slasher.key(),
+        amount: slashed,
+        staked_amount: node.staked_amount,
+        unbonding_amount: node.unbonding_amount,
+        slash_count: node.slash_count,
+        evidence_hash,
+        timestamp: now,
+    });
+    emit_cpi!(ReputationUpdated {
+        node: node.key(),
+        reason: ReputationReason::Slashed,
+        delta: REPUTATION_SLASHED,
+        reputation: node.reputation,
+        timestamp: now,
+    });
+    if deactivated {
+        emit_cpi!(NodeStatusChanged {
+            node: node.key(),
+            is_active: false,
+            timestamp: now,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::{OracleBondChangeKind, OracleBondChanged};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SlashOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle_account.authority.as_ref()],
+        bump = oracle_account.bump
+    )]
+    pub oracle_account: Account<'info, OracleAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"oracle_bond", oracle_account.key().as_ref()],
+        bump = oracle_account.bond_vault_bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = config.treasury_bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// The config admin or an arbiter acting on a dispute outcome.
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Moves `amount` of an oracle's bond to the treasury. Works on suspended
+/// and retired keys too, as long as the bond has not been withdrawn.
+pub fn handler(ctx: Context<SlashOracle>, amount: u64) -> Result<()> {
+    require!(amount > 0, FacilitatorError::InvalidAmount);
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.authority.key;
+    require!(
+        *signer == config.admin || config.is_arbiter(signer),
+        FacilitatorError::Unauthorized
+    );
+
+    let oracle = &mut ctx.accounts.oracle_account;
+    oracle.bonded_amount = oracle
+        .bonded_amount
+        .checked_sub(amount)
+        .ok_or(FacilitatorError::InsufficientBalance)?;
+
+    let oracle_key = oracle.key();
+    let seeds: &[&[u8]] = &[
+        b"oracle_bond",
+        oracle_key.as_ref(),
+        &[oracle.bond_vault_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bond_vault.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+                authority: ctx.accounts.bond_vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(OracleBondChanged {
+        oracle: oracle_key,
+        authority: ctx.accounts.oracle_account.authority,
+        kind: OracleBondChangeKind::Slashed,
+        amount,
+        bonded_amount: ctx.accounts.oracle_account.bonded_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,318 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::constants::REPUTATION_JOB_COMPLETED;
+use crate::errors::FacilitatorError;
+use crate::events::{
+    PaymentSettled, PaymentVerified, ReputationReason, ReputationUpdated, UsageAttested,
+    UsageProofConflicted,
+};
+use crate::state::*;
+use crate::utils::{credit_oracle_fees, id_seed, parse_hash_hex, release_escrow, SettlementSplit};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UsageProofData {
+    pub intent_id: String,
+    pub node_id: String,
+    pub execution_hash: String, // SHA256 hex
+    pub logs_hash: String,      // SHA256 hex
+}
+
+/// Records one oracle's attestation. The escrow settles once
+/// `oracle_quorum` oracles have attested to the same hashes; a mismatching
+/// attestation flags the intent as disputed instead. Intents with a dispute
+/// window are only marked `Verified` here and settle in
+/// `finalize_settlement`.
+///
+/// An attestation that settles must pass the other attestors'
+/// `OracleAccount`s as writable remaining accounts so each is credited its
+/// share of the oracle fee; the share of an attestor removed since goes to
+/// the treasury.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(proof_data: UsageProofData)]
+pub struct SubmitUsageProof<'info> {
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        space = 8 + UsageProof::INIT_SPACE,
+        seeds = [b"proof", &id_seed(&proof_data.intent_id)],
+        bump
+    )]
+    pub usage_proof: Account<'info, UsageProof>,
+
+    #[account(
+        mut,
+        seeds = [b"intent", &id_seed(&proof_data.intent_id)],
+        bump = payment_intent.bump
+    )]
+    pub payment_intent: Account<'info, PaymentIntent>,
+
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&proof_data.node_id)],
+        bump = node.bump
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ FacilitatorError::ProgramPaused
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle.key().as_ref()],
+        bump = oracle_account.bump
+    )]
+    pub oracle_account: Account<'info, OracleAccount>,
+
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", &id_seed(&proof_data.intent_id)],
+        bump = payment_intent.escrow_bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault"],
+        bump = config.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = config.treasury_bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"insurance"],
+        bump = config.insurance_bump
+    )]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: receives the escrow account's rent; must be the paying client.
+    #[account(mut, address = payment_intent.client @ FacilitatorError::InvalidClient)]
+    pub client: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SubmitUsageProof<'info>>,
+    proof_data: UsageProofData,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let execution_hash =
+        parse_hash_hex(&proof_data.execution_hash).ok_or(FacilitatorError::InvalidExecutionHash)?;
+    let logs_hash =
+        parse_hash_hex(&proof_data.logs_hash).ok_or(FacilitatorError::InvalidLogsHash)?;
+
+    require!(
+        ctx.accounts.oracle_account.can_attest(now),
+        FacilitatorError::UnauthorizedOracle
+    );
+    require!(
+        ctx.accounts
+            .oracle_account
+            .meets_bond_minimum(&ctx.accounts.config),
+        FacilitatorError::InsufficientOracleBond
+    );
+
+    let intent = &ctx.accounts.payment_intent;
+    require!(
+        intent.status == PaymentStatus::Authorized,
+        FacilitatorError::InvalidPaymentStatus
+    );
+    require!(now <= intent.expires_at, FacilitatorError::PaymentExpired);
+    if let Some(assigned) = intent.node {
+        require_keys_eq!(
+            assigned,
+            ctx.accounts.node.key(),
+            FacilitatorError::NodeMismatch
+        );
+    }
+    require!(ctx.accounts.node.is_active, FacilitatorError::NodeInactive);
+    require!(!ctx.accounts.node.jailed, FacilitatorError::NodeJailed);
+    require!(
+        ctx.accounts.node.is_live(&ctx.accounts.config, clock.slot),
+        FacilitatorError::NodeOffline
+    );
+    require!(
+        ctx.accounts.node.meets_stake_minimum(&ctx.accounts.config),
+        FacilitatorError::InsufficientNodeStake
+    );
+
+    let node_key = ctx.accounts.node.key();
+    let oracle_key = ctx.accounts.oracle.key();
+    let intent_created_at = ctx.accounts.payment_intent.created_at;
+    let proof = &mut ctx.accounts.usage_proof;
+    if proof.attestors.is_empty() || proof.intent_created_at != intent_created_at {
+        proof.intent_id = proof_data.intent_id;
+        proof.intent_created_at = intent_created_at;
+        proof.node = node_key;
+        proof.execution_hash = execution_hash;
+        proof.logs_hash = logs_hash;
+        proof.attestors = Vec::new();
+        proof.status = ProofStatus::Pending;
+        proof.submitted_at = now;
+        proof.verified = false;
+        proof.bump = ctx.bumps.usage_proof;
+    }
+
+    // Checked before the comparison below, so an oracle cannot contradict
+    // its own attestation to freeze the escrow.
+    let oracle_account = &ctx.accounts.oracle_account;
+    require!(
+        !proof
+            .attestors
+            .iter()
+            .any(|attestor| oracle_account.is_same_oracle(attestor)),
+        FacilitatorError::DuplicateAttestation
+    );
+    if proof.node != node_key
+        || proof.execution_hash != execution_hash
+        || proof.logs_hash != logs_hash
+    {
+        // Oracles disagree: freeze the escrow for dispute resolution rather
+        // than erroring, which would roll the flag back.
+        proof.status = ProofStatus::Conflicted;
+        ctx.accounts.payment_intent.status = PaymentStatus::Disputed;
+
+        emit_cpi!(UsageProofConflicted {
+            usage_proof: proof.key(),
+            payment_intent: ctx.accounts.payment_intent.key(),
+            oracle: oracle_key,
+            timestamp: now,
+        });
+        return Ok(());
+    }
+    proof.attestors.push(oracle_key);
+
+    let quorum = ctx.accounts.config.oracle_quorum;
+    let attestations = proof.attestors.len() as u8;
+    emit_cpi!(UsageAttested {
+        usage_proof: proof.key(),
+        payment_intent: ctx.accounts.payment_intent.key(),
+        oracle: oracle_key,
+        attestations,
+        quorum,
+        timestamp: now,
+    });
+    if attestations < quorum {
+        return Ok(());
+    }
+    proof.status = ProofStatus::Accepted;
+    proof.verified = true;
+
+    let intent = &mut ctx.accounts.payment_intent;
+    // An intent taken with `accept_job` is already counted in `open_jobs`.
+    let newly_assigned = intent.node.is_none();
+    intent.node = Some(node_key);
+    if intent.dispute_window > 0 {
+        // Hold the escrow so the client can still challenge the result.
+        if newly_assigned {
+            ctx.accounts.node.open_job()?;
+        }
+        intent.status = PaymentStatus::Verified;
+        intent.verified_at = Some(now);
+        let dispute_ends_at = intent
+            .dispute_ends_at()
+            .ok_or(FacilitatorError::MathOverflow)?;
+
+        emit_cpi!(PaymentVerified {
+            payment_intent: intent.key(),
+            usage_proof: proof.key(),
+            node: node_key,
+            dispute_ends_at,
+            timestamp: now,
+        });
+        return Ok(());
+    }
+
+    // Split the escrow between the treasury and the reward pool, then hand
+    // its rent back to the client. Oracle fees sit in the reward pool until
+    // claimed.
+    let amount = intent.amount;
+    let uptime_bps = ctx
+        .accounts
+        .node
+        .uptime_bps(&ctx.accounts.config, clock.slot);
+    let mut split = SettlementSplit::new(
+        &ctx.accounts.config,
+        amount,
+        proof.attestors.len(),
+        ctx.accounts.treasury.amount,
+    )?;
+    let forfeited = credit_oracle_fees(
+        &proof.attestors,
+        split.oracle_share,
+        Some(&mut ctx.accounts.oracle_account),
+        ctx.remaining_accounts,
+    )?;
+    split.forfeit_oracle_fees(forfeited);
+    split.withhold_for_downtime(&ctx.accounts.config, uptime_bps)?;
+    release_escrow(
+        intent,
+        &ctx.accounts.escrow,
+        &[
+            (
+                ctx.accounts.treasury.to_account_info(),
+                split.fee - split.insurance_fee,
+            ),
+            (
+                ctx.accounts.insurance_vault.to_account_info(),
+                split.insurance_fee + split.uptime_withheld,
+            ),
+            (
+                ctx.accounts.reward_vault.to_account_info(),
+                split.node_amount + split.oracle_fee,
+            ),
+        ],
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.client.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    )?;
+    let node = &mut ctx.accounts.node;
+    node.record_settlement(split.node_amount, now)?;
+    if !newly_assigned {
+        node.close_job();
+    }
+
+    let intent = &mut ctx.accounts.payment_intent;
+    intent.status = PaymentStatus::Completed;
+    intent.settled_at = Some(now);
+
+    emit_cpi!(PaymentSettled {
+        payment_intent: intent.key(),
+        usage_proof: proof.key(),
+        intent_id: intent.intent_id.clone(),
+        node: node_key,
+        attestors: proof.attestors.clone(),
+        amount,
+        fee: split.fee,
+        insurance_fee: split.insurance_fee,
+        oracle_fee: split.oracle_fee,
+        uptime_withheld: split.uptime_withheld,
+        node_amount: split.node_amount,
+        timestamp: now,
+    });
+    emit_cpi!(ReputationUpdated {
+        node: ctx.accounts.node.key(),
+        reason: ReputationReason::JobCompleted,
+        delta: REPUTATION_JOB_COMPLETED,
+        reputation: ctx.accounts.node.reputation,
+        timestamp: now,
+    });
+    Ok(())
+}
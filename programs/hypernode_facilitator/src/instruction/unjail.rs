@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::NodeUnjailed;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Unjail<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = owner @ FacilitatorError::NotNodeOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Releases a jailed node once `jail_cooldown` has passed, provided it is
+/// still staked at the minimum. The failure streak starts over.
+pub fn handler(ctx: Context<Unjail>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.config;
+    let node = &mut ctx.accounts.node;
+    require!(node.jailed, FacilitatorError::NodeNotJailed);
+    let released_at = node
+        .jailed_at
+        .checked_add(config.jail_cooldown)
+        .ok_or(FacilitatorError::MathOverflow)?;
+    require!(now >= released_at, FacilitatorError::JailCooldownActive);
+    require!(
+        node.meets_stake_minimum(config),
+        FacilitatorError::InsufficientNodeStake
+    );
+    node.jailed = false;
+    node.consecutive_failures = 0;
+
+    emit_cpi!(NodeUnjailed {
+        node: node.key(),
+        owner: node.owner,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ FacilitatorError::Unauthorized
+    )]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Replaces the tunable parameters. `heartbeat_epoch_slots` must stay as
+/// initialized: uptime already recorded would be measured in the wrong epochs.
+pub fn handler(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(
+        params.heartbeat_epoch_slots == config.heartbeat_epoch_slots,
+        FacilitatorError::InvalidConfig
+    );
+    config.apply(params)
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::errors::FacilitatorError;
+use crate::events::NodeProfileUpdated;
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateNodeProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = owner @ FacilitatorError::NotNodeOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateNodeProfile>, profile: NodeProfile) -> Result<()> {
+    profile.validate()?;
+
+    let node = &mut ctx.accounts.node;
+    require!(
+        profile.node_id == node.node_id,
+        FacilitatorError::NodeIdImmutable
+    );
+    node.set_profile(profile);
+
+    emit_cpi!(NodeProfileUpdated {
+        node: node.key(),
+        endpoint: node.endpoint.clone(),
+        region: node.region.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
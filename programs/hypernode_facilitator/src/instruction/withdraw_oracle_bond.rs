@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::{OracleBondChangeKind, OracleBondChanged};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawOracleBond<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", authority.key().as_ref()],
+        bump = oracle_account.bump
+    )]
+    pub oracle_account: Account<'info, OracleAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"oracle_bond", oracle_account.key().as_ref()],
+        bump = oracle_account.bond_vault_bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FacilitatorConfig>,
+
+    #[account(
+        mut,
+        token::mint = bond_vault.mint,
+        token::authority = authority
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Returns the whole bond once the key has been suspended or rotated out
+/// for at least `unbonding_period`, so misbehaviour found late can still
+/// be slashed.
+pub fn handler(ctx: Context<WithdrawOracleBond>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let oracle = &ctx.accounts.oracle_account;
+    let amount = oracle.bonded_amount;
+    require!(amount > 0, FacilitatorError::NothingToWithdraw);
+    let unlocks_at = oracle
+        .bond_unlocks_at(&ctx.accounts.config)
+        .ok_or(FacilitatorError::OracleBondLocked)?;
+    require!(now >= unlocks_at, FacilitatorError::OracleBondLocked);
+
+    let oracle_key = oracle.key();
+    let seeds: &[&[u8]] = &[
+        b"oracle_bond",
+        oracle_key.as_ref(),
+        &[oracle.bond_vault_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bond_vault.to_account_info(),
+                to: ctx.accounts.authority_token_account.to_account_info(),
+                authority: ctx.accounts.bond_vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    let oracle = &mut ctx.accounts.oracle_account;
+    oracle.bonded_amount = 0;
+
+    emit_cpi!(OracleBondChanged {
+        oracle: oracle_key,
+        authority: oracle.authority,
+        kind: OracleBondChangeKind::Withdrawn,
+        amount,
+        bonded_amount: 0,
+        timestamp: now,
+    });
+    Ok(())
+}
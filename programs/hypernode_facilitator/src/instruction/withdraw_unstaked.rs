@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::FacilitatorError;
+use crate::events::{StakeChangeKind, StakeChanged};
+use crate::state::*;
+use crate::utils::id_seed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawUnstaked<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", &id_seed(&node.node_id)],
+        bump = node.bump,
+        has_one = owner @ FacilitatorError::NotNodeOwner
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", node.key().as_ref()],
+        bump = node.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = vault.mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawUnstaked>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let amount = ctx.accounts.node.unbonding_amount;
+    require!(amount > 0, FacilitatorError::NothingToWithdraw);
+    require!(
+        now >= ctx.accounts.node.unbonding_ready_at,
+        FacilitatorError::UnbondingInProgress
+    );
+
+    let node_key = ctx.accounts.node.key();
+    let seeds: &[&[u8]] = &[b"vault", node_key.as_ref(), &[ctx.accounts.node.vault_bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    let node = &mut ctx.accounts.node;
+    node.unbonding_amount = 0;
+    node.unbonding_ready_at = 0;
+
+    emit_cpi!(StakeChanged {
+        node: node.key(),
+        owner: node.owner,
+        kind: StakeChangeKind::Withdrawn,
+        amount,
+        staked_amount: node.staked_amount,
+        unbonding_amount: node.unbonding_amount,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -1,16 +1,185 @@
 use anchor_lang::prelude::*;
 
+pub mod constants;
+pub mod errors;
+pub mod events;
 pub mod instruction;
 pub mod state;
+pub mod utils;
 
 use instruction::*;
+use state::{ConfigParams, NodeProfile, OracleStatus};
 
 #[program]
 pub mod hypernode_facilitator {
     use super::*;
-    pub fn register_node(ctx: Context<RegisterNode>) -> Result<()> {
-        register_node::handler(ctx)
+    pub fn initialize_config(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+        initialize_config::handler(ctx, params)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+        update_config::handler(ctx, params)
+    }
+
+    pub fn register_node(ctx: Context<RegisterNode>, profile: NodeProfile) -> Result<()> {
+        register_node::handler(ctx, profile)
+    }
+
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        stake::handler(ctx, amount)
+    }
+
+    pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+        request_unstake::handler(ctx, amount)
+    }
+
+    pub fn withdraw_unstaked(ctx: Context<WithdrawUnstaked>) -> Result<()> {
+        withdraw_unstaked::handler(ctx)
+    }
+
+    pub fn authorize_payment(
+        ctx: Context<AuthorizePayment>,
+        intent_data: PaymentIntentData,
+    ) -> Result<()> {
+        authorize_payment::handler(ctx, intent_data)
+    }
+
+    pub fn submit_usage_proof<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SubmitUsageProof<'info>>,
+        proof_data: UsageProofData,
+    ) -> Result<()> {
+        submit_usage_proof::handler(ctx, proof_data)
+    }
+
+    pub fn claim_rewards(ctx: Context<ClaimRewards>, amount: u64) -> Result<()> {
+        claim_rewards::handler(ctx, amount)
+    }
+
+    pub fn cancel_payment(ctx: Context<CancelPayment>) -> Result<()> {
+        cancel_payment::handler(ctx)
+    }
+
+    pub fn refund_expired(ctx: Context<RefundExpired>) -> Result<()> {
+        refund_expired::handler(ctx)
+    }
+
+    pub fn update_node_profile(
+        ctx: Context<UpdateNodeProfile>,
+        profile: NodeProfile,
+    ) -> Result<()> {
+        update_node_profile::handler(ctx, profile)
+    }
+
+    pub fn deactivate_node(ctx: Context<DeactivateNode>) -> Result<()> {
+        deactivate_node::handler(ctx)
+    }
+
+    pub fn reactivate_node(ctx: Context<ReactivateNode>) -> Result<()> {
+        reactivate_node::handler(ctx)
+    }
+
+    pub fn close_node(ctx: Context<CloseNode>) -> Result<()> {
+        close_node::handler(ctx)
+    }
+
+    pub fn set_operator(ctx: Context<SetOperator>, operator: Pubkey) -> Result<()> {
+        set_operator::handler(ctx, operator)
+    }
+
+    pub fn accept_job(ctx: Context<AcceptJob>) -> Result<()> {
+        accept_job::handler(ctx)
+    }
+
+    pub fn propose_owner_transfer(
+        ctx: Context<ProposeOwnerTransfer>,
+        new_owner: Option<Pubkey>,
+    ) -> Result<()> {
+        propose_owner_transfer::handler(ctx, new_owner)
+    }
+
+    pub fn accept_owner_transfer(ctx: Context<AcceptOwnerTransfer>) -> Result<()> {
+        accept_owner_transfer::handler(ctx)
+    }
+
+    pub fn add_oracle(ctx: Context<AddOracle>, authority: Pubkey) -> Result<()> {
+        add_oracle::handler(ctx, authority)
+    }
+
+    pub fn set_oracle_status(ctx: Context<SetOracleStatus>, status: OracleStatus) -> Result<()> {
+        set_oracle_status::handler(ctx, status)
+    }
+
+    pub fn remove_oracle(ctx: Context<RemoveOracle>) -> Result<()> {
+        remove_oracle::handler(ctx)
+    }
+
+    pub fn rotate_oracle(ctx: Context<RotateOracle>, new_authority: Pubkey) -> Result<()> {
+        rotate_oracle::handler(ctx, new_authority)
+    }
+
+    pub fn bond_oracle(ctx: Context<BondOracle>, amount: u64) -> Result<()> {
+        bond_oracle::handler(ctx, amount)
+    }
+
+    pub fn withdraw_oracle_bond(ctx: Context<WithdrawOracleBond>) -> Result<()> {
+        withdraw_oracle_bond::handler(ctx)
+    }
+
+    pub fn claim_oracle_fees(ctx: Context<ClaimOracleFees>, amount: u64) -> Result<()> {
+        claim_oracle_fees::handler(ctx, amount)
+    }
+
+    pub fn slash_oracle(ctx: Context<SlashOracle>, amount: u64) -> Result<()> {
+        slash_oracle::handler(ctx, amount)
+    }
+
+    pub fn open_dispute(ctx: Context<OpenDispute>, evidence_hash: String) -> Result<()> {
+        open_dispute::handler(ctx, evidence_hash)
+    }
+
+    pub fn finalize_settlement<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeSettlement<'info>>,
+    ) -> Result<()> {
+        finalize_settlement::handler(ctx)
+    }
+
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        resolution: DisputeResolution,
+    ) -> Result<()> {
+        resolve_dispute::handler(ctx, resolution)
+    }
+
+    pub fn slash_node<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SlashNode<'info>>,
+        evidence_hash: String,
+    ) -> Result<()> {
+        slash_node::handler(ctx, evidence_hash)
+    }
+
+    pub fn compensate_client(ctx: Context<CompensateClient>, amount: u64) -> Result<()> {
+        compensate_client::handler(ctx, amount)
+    }
+
+    pub fn unjail(ctx: Context<Unjail>) -> Result<()> {
+        unjail::handler(ctx)
+    }
+
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        heartbeat::handler(ctx)
+    }
+
+    pub fn mark_node_offline(ctx: Context<MarkNodeOffline>) -> Result<()> {
+        mark_node_offline::handler(ctx)
     }
 }
 
-use instruction::register_node;
+use instruction::{
+    accept_job, accept_owner_transfer, add_oracle, authorize_payment, bond_oracle, cancel_payment,
+    claim_oracle_fees, claim_rewards, close_node, compensate_client, deactivate_node,
+    finalize_settlement, heartbeat, initialize_config, mark_node_offline, open_dispute,
+    propose_owner_transfer, reactivate_node, refund_expired, register_node, remove_oracle,
+    request_unstake, resolve_dispute, rotate_oracle, set_operator, set_oracle_status, slash_node,
+    slash_oracle, stake, submit_usage_proof, unjail, update_config, update_node_profile,
+    withdraw_oracle_bond, withdraw_unstaked,
+};
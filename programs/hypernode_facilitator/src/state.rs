@@ -1,9 +1,627 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    BPS_DENOMINATOR, ENDPOINT_MAX_LEN, GPU_MODEL_MAX_LEN, MAX_ARBITERS, MAX_ATTESTATIONS,
+    MAX_DISPUTE_WINDOW, MAX_PAYMENT_TIMEOUT, NODE_ID_MAX_LEN, REGION_MAX_LEN, REPUTATION_BASELINE,
+    REPUTATION_HALF_LIFE, REPUTATION_JOB_COMPLETED, REPUTATION_MAX, REPUTATION_SLASHED,
+};
+use crate::errors::FacilitatorError;
+use crate::utils::{bps_of, half_life_decay};
+
+/// Program-wide settings, stored at `["config"]`.
+#[account]
+#[derive(InitSpace)]
+pub struct FacilitatorConfig {
+    pub admin: Pubkey,
+    pub hyper_mint: Pubkey,
+    pub stake_minimum: u64,
+    pub max_payment_timeout: i64,
+    /// Seconds unstaked HYPER stays locked (and slashable) before withdrawal.
+    pub unbonding_period: i64,
+    /// Longest challenge period a client may put on a payment intent.
+    pub max_dispute_window: i64,
+    pub fee_bps: u16,
+    /// Treasury balance above which protocol fees go to the insurance
+    /// vault instead.
+    pub insurance_fee_threshold: u64,
+    /// Cut of each settlement shared by the oracles that attested to it.
+    pub oracle_fee_bps: u16,
+    /// Bond an oracle must hold before its attestations are accepted.
+    pub oracle_min_bond: u64,
+    /// Seconds a rotated-out oracle key stays valid next to its successor.
+    pub oracle_rotation_overlap: i64,
+    /// Matching oracle attestations needed before a usage proof settles.
+    pub oracle_quorum: u8,
+    /// Share of a node's stake taken by each `slash_node`.
+    pub slash_bps: u16,
+    /// Consecutive failed jobs that jail a node; 0 disables jailing.
+    pub jail_threshold: u16,
+    /// Seconds a jailed node must wait before `unjail`.
+    pub jail_cooldown: i64,
+    /// Length of a heartbeat epoch, in slots. Fixed once the config is
+    /// initialized, since `Node::uptime_epochs` counts epochs of this length.
+    pub heartbeat_epoch_slots: u64,
+    /// Missed heartbeat epochs after which a node can be marked offline.
+    pub max_missed_heartbeats: u8,
+    /// Share of a node's settlement earnings that rides on its uptime. The
+    /// part matching the heartbeat epochs it missed goes to the insurance
+    /// vault instead.
+    pub uptime_reward_bps: u16,
+    /// Council allowed to resolve disputes and slash on their outcome.
+    #[max_len(5)]
+    pub arbiters: Vec<Pubkey>,
+    /// Blocks new registrations, stake, payments and settlements. Refunds
+    /// and withdrawals stay open so funds are never trapped.
+    pub paused: bool,
+    pub bump: u8,
+    pub reward_vault_bump: u8,
+    pub treasury_bump: u8,
+    pub insurance_bump: u8,
+}
+
+/// Admin-tunable subset of `FacilitatorConfig`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigParams {
+    pub stake_minimum: u64,
+    pub max_payment_timeout: i64,
+    pub unbonding_period: i64,
+    pub max_dispute_window: i64,
+    pub fee_bps: u16,
+    pub insurance_fee_threshold: u64,
+    pub oracle_fee_bps: u16,
+    pub oracle_min_bond: u64,
+    pub oracle_rotation_overlap: i64,
+    pub oracle_quorum: u8,
+    pub slash_bps: u16,
+    pub jail_threshold: u16,
+    pub jail_cooldown: i64,
+    pub heartbeat_epoch_slots: u64,
+    pub max_missed_heartbeats: u8,
+    pub uptime_reward_bps: u16,
+    pub arbiters: Vec<Pubkey>,
+    pub paused: bool,
+}
+
+impl FacilitatorConfig {
+    pub fn apply(&mut self, params: ConfigParams) -> Result<()> {
+        require!(
+            params.max_payment_timeout > 0 && params.max_payment_timeout <= MAX_PAYMENT_TIMEOUT,
+            FacilitatorError::InvalidConfig
+        );
+        require!(
+            params.unbonding_period >= 0,
+            FacilitatorError::InvalidConfig
+        );
+        require!(
+            params.max_dispute_window >= 0 && params.max_dispute_window <= MAX_DISPUTE_WINDOW,
+            FacilitatorError::InvalidConfig
+        );
+        require!(
+            u64::from(params.fee_bps) + u64::from(params.oracle_fee_bps) <= BPS_DENOMINATOR,
+            FacilitatorError::InvalidConfig
+        );
+        require!(
+            params.oracle_rotation_overlap >= 0,
+            FacilitatorError::InvalidConfig
+        );
+        require!(
+            params.oracle_quorum >= 1 && usize::from(params.oracle_quorum) <= MAX_ATTESTATIONS,
+            FacilitatorError::InvalidConfig
+        );
+        require!(
+            u64::from(params.slash_bps) <= BPS_DENOMINATOR,
+            FacilitatorError::InvalidConfig
+        );
+        require!(params.jail_cooldown >= 0, FacilitatorError::InvalidConfig);
+        require!(
+            params.heartbeat_epoch_slots > 0 && params.max_missed_heartbeats > 0,
+            FacilitatorError::InvalidConfig
+        );
+        require!(
+            u64::from(params.uptime_reward_bps) <= BPS_DENOMINATOR,
+            FacilitatorError::InvalidConfig
+        );
+        require!(
+            params.arbiters.len() <= MAX_ARBITERS,
+            FacilitatorError::InvalidConfig
+        );
+        for (i, arbiter) in params.arbiters.iter().enumerate() {
+            require!(
+                *arbiter != Pubkey::default() && !params.arbiters[..i].contains(arbiter),
+                FacilitatorError::InvalidConfig
+            );
+        }
+        // Conflicting attestations and client disputes freeze an escrow
+        // until an arbiter resolves it, so either needs someone to do that.
+        require!(
+            (params.oracle_quorum == 1 && params.max_dispute_window == 0)
+                || !params.arbiters.is_empty(),
+            FacilitatorError::InvalidConfig
+        );
+
+        self.stake_minimum = params.stake_minimum;
+        self.max_payment_timeout = params.max_payment_timeout;
+        self.unbonding_period = params.unbonding_period;
+        self.max_dispute_window = params.max_dispute_window;
+        self.fee_bps = params.fee_bps;
+        self.insurance_fee_threshold = params.insurance_fee_threshold;
+        self.oracle_fee_bps = params.oracle_fee_bps;
+        self.oracle_min_bond = params.oracle_min_bond;
+        self.oracle_rotation_overlap = params.oracle_rotation_overlap;
+        self.oracle_quorum = params.oracle_quorum;
+        self.slash_bps = params.slash_bps;
+        self.jail_threshold = params.jail_threshold;
+        self.jail_cooldown = params.jail_cooldown;
+        self.heartbeat_epoch_slots = params.heartbeat_epoch_slots;
+        self.max_missed_heartbeats = params.max_missed_heartbeats;
+        self.uptime_reward_bps = params.uptime_reward_bps;
+        self.arbiters = params.arbiters;
+        self.paused = params.paused;
+        Ok(())
+    }
+
+    pub fn is_arbiter(&self, key: &Pubkey) -> bool {
+        self.arbiters.contains(key)
+    }
+
+    /// Protocol cut of a settled `amount`, rounded down.
+    pub fn protocol_fee(&self, amount: u64) -> Result<u64> {
+        bps_of(amount, self.fee_bps)
+    }
+
+    /// Oracle cut of a settled `amount`, before it is split between the
+    /// attestors.
+    pub fn oracle_fee(&self, amount: u64) -> Result<u64> {
+        bps_of(amount, self.oracle_fee_bps)
+    }
+}
+
 #[account]
+#[derive(InitSpace)]
 pub struct Node {
+    /// Cold key: controls stake, rewards, profile and the operator key.
     pub owner: Pubkey,
+    /// Active stake. Only this counts towards the configured minimum.
     pub staked_amount: u64,
     pub pending_reward: u64,
+    /// Stake waiting out the unbonding period. Still held in the vault.
+    pub unbonding_amount: u64,
+    pub unbonding_ready_at: i64,
+    pub bump: u8,
+    pub vault_bump: u8,
+    #[max_len(64)]
+    pub node_id: String,
+    /// Public URL jobs are dispatched to.
+    #[max_len(128)]
+    pub endpoint: String,
+    #[max_len(32)]
+    pub region: String,
+    pub hardware: NodeHardware,
+    pub total_earned: u64,
+    pub jobs_completed: u64,
+    pub is_active: bool,
+    pub registered_at: i64,
+    /// Slot of registration; uptime is measured from its heartbeat epoch.
+    pub registered_slot: u64,
+    /// Hot key kept on the compute box. May accept jobs and send liveness
+    /// signals, but cannot move funds.
+    pub operator: Pubkey,
+    /// Proposed new owner, set by `propose_owner_transfer`.
+    pub pending_owner: Option<Pubkey>,
+    pub slash_count: u32,
+    pub total_slashed: u64,
+    pub last_slashed_at: i64,
+    /// Failed or refunded jobs since the last settlement.
+    pub consecutive_failures: u16,
+    /// Jailed nodes cannot take or settle jobs until `unjail`.
+    pub jailed: bool,
+    pub jailed_at: i64,
+    /// Score out of `REPUTATION_MAX`; see `adjust_reputation`.
+    pub reputation: u16,
+    pub reputation_updated_at: i64,
+    /// `reputation` in 32.32 fixed point. Decay is applied to this value so
+    /// that frequent small steps are not lost to rounding.
+    pub reputation_fixed: u64,
+    /// Slot of the operator's last `heartbeat`.
+    pub last_heartbeat_slot: u64,
+    /// Heartbeat epochs in which the node checked in at least once,
+    /// counting the one it registered in.
+    pub uptime_epochs: u64,
+    /// Intents assigned to this node whose escrow is still held. The node
+    /// cannot be closed while any remain.
+    pub open_jobs: u32,
+}
+
+impl Node {
+    /// Nodes below the minimum stake must not be handed work.
+    pub fn meets_stake_minimum(&self, config: &FacilitatorConfig) -> bool {
+        self.staked_amount >= config.stake_minimum
+    }
+
+    /// Whole heartbeat epochs that have passed since the last heartbeat.
+    pub fn missed_heartbeats(&self, config: &FacilitatorConfig, slot: u64) -> u64 {
+        slot.saturating_sub(self.last_heartbeat_slot) / config.heartbeat_epoch_slots
+    }
+
+    /// Nodes that stopped sending heartbeats must not be handed work.
+    pub fn is_live(&self, config: &FacilitatorConfig, slot: u64) -> bool {
+        self.missed_heartbeats(config, slot) < u64::from(config.max_missed_heartbeats)
+    }
+
+    /// Share of the heartbeat epochs since registration, the current one
+    /// included, in which the node checked in, in basis points.
+    pub fn uptime_bps(&self, config: &FacilitatorConfig, slot: u64) -> u16 {
+        let epoch_slots = config.heartbeat_epoch_slots;
+        let epochs = (slot / epoch_slots).saturating_sub(self.registered_slot / epoch_slots) + 1;
+        let uptime =
+            u128::from(self.uptime_epochs) * u128::from(BPS_DENOMINATOR) / u128::from(epochs);
+        uptime.min(u128::from(BPS_DENOMINATOR)) as u16
+    }
+
+    /// Books the node's share of a settled job.
+    pub fn record_settlement(&mut self, node_amount: u64, now: i64) -> Result<()> {
+        self.pending_reward = self
+            .pending_reward
+            .checked_add(node_amount)
+            .ok_or(FacilitatorError::MathOverflow)?;
+        self.total_earned = self
+            .total_earned
+            .checked_add(node_amount)
+            .ok_or(FacilitatorError::MathOverflow)?;
+        self.jobs_completed = self
+            .jobs_completed
+            .checked_add(1)
+            .ok_or(FacilitatorError::MathOverflow)?;
+        self.consecutive_failures = 0;
+        self.adjust_reputation(REPUTATION_JOB_COMPLETED, now);
+        Ok(())
+    }
+
+    /// Counts an intent newly assigned to this node, by `accept_job` or by
+    /// a usage proof that leaves the escrow held.
+    pub fn open_job(&mut self) -> Result<()> {
+        self.open_jobs = self
+            .open_jobs
+            .checked_add(1)
+            .ok_or(FacilitatorError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Releases a job counted by `open_job` once its escrow is paid out or
+    /// refunded.
+    pub fn close_job(&mut self) {
+        self.open_jobs = self.open_jobs.saturating_sub(1);
+    }
+
+    /// Decays the reputation toward `REPUTATION_BASELINE` for the time since
+    /// its last update, then applies `delta`, clamped to the valid range.
+    /// The decay is exponential, so the result does not depend on how often
+    /// the score is touched.
+    pub fn adjust_reputation(&mut self, delta: i32, now: i64) {
+        let elapsed = now.saturating_sub(self.reputation_updated_at);
+        let baseline = i128::from(REPUTATION_BASELINE) << 32;
+        let gap = i128::from(self.reputation_fixed) - baseline;
+        let decayed = half_life_decay(gap.unsigned_abs(), elapsed, REPUTATION_HALF_LIFE);
+        let next = baseline + gap.signum() * decayed as i128 + (i128::from(delta) << 32);
+        let next = next.clamp(0, i128::from(REPUTATION_MAX) << 32);
+        self.reputation_fixed = next as u64;
+        self.reputation = ((next + (1 << 31)) >> 32) as u16;
+        self.reputation_updated_at = now;
+    }
+
+    /// Counts a failed or refunded job and jails the node once
+    /// `jail_threshold` failures in a row are reached. Returns whether this
+    /// failure jailed it.
+    pub fn record_failure(&mut self, config: &FacilitatorConfig, now: i64) -> bool {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        if self.jailed
+            || config.jail_threshold == 0
+            || self.consecutive_failures < config.jail_threshold
+        {
+            return false;
+        }
+        self.jailed = true;
+        self.jailed_at = now;
+        true
+    }
+
+    /// Takes up to `amount` from the node's stake, drawing on unbonding
+    /// funds once the active stake runs out, and records it in the node's
+    /// slash history. Returns what was taken.
+    pub fn slash(&mut self, amount: u64, now: i64) -> Result<u64> {
+        let from_stake = amount.min(self.staked_amount);
+        let from_unbonding = (amount - from_stake).min(self.unbonding_amount);
+        let slashed = from_stake + from_unbonding;
+        if slashed == 0 {
+            return Ok(0);
+        }
+        self.staked_amount -= from_stake;
+        self.unbonding_amount -= from_unbonding;
+        self.slash_count = self
+            .slash_count
+            .checked_add(1)
+            .ok_or(FacilitatorError::MathOverflow)?;
+        self.total_slashed = self
+            .total_slashed
+            .checked_add(slashed)
+            .ok_or(FacilitatorError::MathOverflow)?;
+        self.last_slashed_at = now;
+        self.adjust_reputation(REPUTATION_SLASHED, now);
+        Ok(slashed)
+    }
+
+    /// Deactivates a node whose stake fell below the minimum. Returns
+    /// whether the node was switched off.
+    pub fn deactivate_if_understaked(&mut self, config: &FacilitatorConfig) -> bool {
+        if !self.is_active || self.meets_stake_minimum(config) {
+            return false;
+        }
+        self.is_active = false;
+        true
+    }
+
+    /// Copies the mutable parts of `profile` onto the node. The node id is
+    /// fixed at registration.
+    pub fn set_profile(&mut self, profile: NodeProfile) {
+        self.endpoint = profile.endpoint;
+        self.region = profile.region;
+        self.hardware = profile.hardware;
+    }
+}
+
+/// Registry entry for a key trusted to submit usage proofs, stored at
+/// `["oracle", authority]`.
+#[account]
+#[derive(InitSpace)]
+pub struct OracleAccount {
+    pub authority: Pubkey,
+    pub status: OracleStatus,
+    pub added_at: i64,
+    /// Set when the key is rotated out; it keeps attesting until then.
+    pub retires_at: Option<i64>,
+    pub successor: Option<Pubkey>,
+    /// Key this one replaced. During the rotation overlap both keys can
+    /// attest, but they count as a single oracle.
+    pub predecessor: Option<Pubkey>,
+    /// When `predecessor` stops attesting. This key cannot be rotated out
+    /// before then, or the predecessor and the next key would count as two
+    /// oracles.
+    pub predecessor_retires_at: Option<i64>,
+    pub status_changed_at: i64,
+    /// HYPER held in the oracle's bond vault; slashable.
+    pub bonded_amount: u64,
+    /// Attestation fees earned and not yet claimed.
+    pub pending_fees: u64,
+    pub bump: u8,
+    pub bond_vault_bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum OracleStatus {
+    Active,
+    Suspended,
+}
+
+impl OracleAccount {
+    pub fn can_attest(&self, now: i64) -> bool {
+        self.status == OracleStatus::Active && self.retires_at.map_or(true, |at| now < at)
+    }
+
+    pub fn meets_bond_minimum(&self, config: &FacilitatorConfig) -> bool {
+        self.bonded_amount >= config.oracle_min_bond
+    }
+
+    /// Whether `authority` is this oracle, under this key or the one it
+    /// was rotated from or to.
+    pub fn is_same_oracle(&self, authority: &Pubkey) -> bool {
+        self.authority == *authority
+            || self.successor == Some(*authority)
+            || self.predecessor == Some(*authority)
+    }
+
+    /// When the bond may be withdrawn. The key has to be suspended or
+    /// rotated out first, and the bond then stays slashable for one
+    /// unbonding period.
+    pub fn bond_unlocks_at(&self, config: &FacilitatorConfig) -> Option<i64> {
+        let since = match (self.status, self.retires_at) {
+            (OracleStatus::Suspended, _) => self.status_changed_at,
+            (_, Some(retires_at)) => retires_at,
+            _ => return None,
+        };
+        since.checked_add(config.unbonding_period)
+    }
+}
+
+/// Per-owner index over the nodes a wallet operates, stored at
+/// `["operator", owner]`. Individual nodes are found with a
+/// `getProgramAccounts` memcmp on `Node.owner` (offset 8).
+#[account]
+#[derive(InitSpace)]
+pub struct OperatorAccount {
+    pub owner: Pubkey,
+    pub node_count: u32,
+    pub bump: u8,
+}
+
+/// Hardware a node declares so schedulers can match jobs to it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct NodeHardware {
+    pub cpu_cores: u16,
+    pub ram_gb: u32,
+    #[max_len(32)]
+    pub gpu_model: String,
+    pub gpu_count: u8,
+    /// Per GPU.
+    pub vram_gb: u32,
+}
+
+/// Operator-supplied description of a node, taken by `register_node` and
+/// `update_node_profile`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct NodeProfile {
+    pub node_id: String,
+    pub endpoint: String,
+    pub region: String,
+    pub hardware: NodeHardware,
+}
+
+impl NodeProfile {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.node_id.is_empty() && self.node_id.len() <= NODE_ID_MAX_LEN,
+            FacilitatorError::InvalidNodeId
+        );
+        require!(
+            !self.endpoint.is_empty() && self.endpoint.len() <= ENDPOINT_MAX_LEN,
+            FacilitatorError::InvalidEndpoint
+        );
+        require!(
+            self.region.len() <= REGION_MAX_LEN
+                && self.hardware.gpu_model.len() <= GPU_MODEL_MAX_LEN,
+            FacilitatorError::InvalidNodeProfile
+        );
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentIntent {
+    #[max_len(64)]
+    pub intent_id: String,
+    pub client: Pubkey,
+    pub amount: u64,
+    pub status: PaymentStatus,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub settled_at: Option<i64>,
+    /// Node that accepted the job, or that the escrow was released to.
+    pub node: Option<Pubkey>,
     pub bump: u8,
+    pub escrow_bump: u8,
+    /// Seconds the client may dispute a verified proof before the escrow
+    /// is released; 0 settles as soon as the proof reaches quorum.
+    pub dispute_window: i64,
+    pub verified_at: Option<i64>,
+    /// Hash of the client's off-chain evidence, set by `open_dispute`.
+    pub evidence_hash: Option<[u8; 32]>,
+    /// Hash of the arbiters' written decision, set by `resolve_dispute`.
+    pub resolution_hash: Option<[u8; 32]>,
+    /// Set by `resolve_dispute` when the node lost the dispute; only then
+    /// may the client be compensated.
+    pub node_lost: bool,
+    /// Paid to the client from the insurance vault on top of the escrow.
+    pub compensated: u64,
+    /// Set once this intent has been used as grounds to slash its node, so
+    /// it cannot back a second slash.
+    pub slashed: bool,
+}
+
+impl PaymentIntent {
+    /// End of the challenge period, once the usage proof has been verified.
+    pub fn dispute_ends_at(&self) -> Option<i64> {
+        self.verified_at?.checked_add(self.dispute_window)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PaymentStatus {
+    Pending,
+    Authorized,
+    Completed,
+    Refunded,
+    /// Oracles disagreed on the usage proof, or the client challenged it;
+    /// escrow is frozen.
+    Disputed,
+    /// Proof reached quorum; escrow is held until the dispute window ends.
+    Verified,
+    /// Dispute settled by the arbiters; see `resolution_hash`.
+    Resolved,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UsageProof {
+    #[max_len(64)]
+    pub intent_id: String,
+    /// `created_at` of the intent being attested. Tells a live proof apart
+    /// from one left behind by a cancelled intent whose id was reused.
+    pub intent_created_at: i64,
+    pub node: Pubkey,
+    pub execution_hash: [u8; 32],
+    pub logs_hash: [u8; 32],
+    /// Oracles that attested to exactly these hashes for this node.
+    #[max_len(5)]
+    pub attestors: Vec<Pubkey>,
+    pub status: ProofStatus,
+    pub submitted_at: i64,
+    pub verified: bool,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ProofStatus {
+    Pending,
+    Accepted,
+    Conflicted,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A node at the reputation baseline, last updated at time 0. All-zero
+    /// bytes decode to empty strings and unset options.
+    fn baseline_node() -> Node {
+        let mut node = Node::deserialize(&mut &[0u8; 1024][..]).unwrap();
+        node.adjust_reputation(i32::from(REPUTATION_BASELINE), 0);
+        node
+    }
+
+    #[test]
+    fn adjust_reputation_applies_the_delta() {
+        let mut node = baseline_node();
+        assert_eq!(node.reputation, REPUTATION_BASELINE);
+        node.adjust_reputation(1_000, 0);
+        assert_eq!(node.reputation, 6_000);
+        node.adjust_reputation(-2_000, 0);
+        assert_eq!(node.reputation, 4_000);
+    }
+
+    #[test]
+    fn adjust_reputation_decays_toward_the_baseline() {
+        let mut above = baseline_node();
+        above.adjust_reputation(2_000, 0);
+        above.adjust_reputation(0, REPUTATION_HALF_LIFE);
+        assert_eq!(above.reputation, 6_000);
+        assert_eq!(above.reputation_updated_at, REPUTATION_HALF_LIFE);
+
+        let mut below = baseline_node();
+        below.adjust_reputation(-2_000, 0);
+        below.adjust_reputation(0, 2 * REPUTATION_HALF_LIFE);
+        assert_eq!(below.reputation, 4_500);
+    }
+
+    #[test]
+    fn adjust_reputation_clamps_to_the_valid_range() {
+        let mut node = baseline_node();
+        node.adjust_reputation(i32::from(REPUTATION_MAX), 0);
+        assert_eq!(node.reputation, REPUTATION_MAX);
+        node.adjust_reputation(-2 * i32::from(REPUTATION_MAX), 0);
+        assert_eq!(node.reputation, 0);
+        assert_eq!(node.reputation_fixed, 0);
+    }
+
+    #[test]
+    fn adjust_reputation_does_not_depend_on_update_frequency() {
+        let mut hourly = baseline_node();
+        hourly.adjust_reputation(3_000, 0);
+        let mut once = hourly.clone();
+        for hour in 1..=720 {
+            hourly.adjust_reputation(0, hour * 3_600);
+        }
+        once.adjust_reputation(0, 720 * 3_600);
+        assert_eq!(once.reputation, 6_500);
+        assert!(hourly.reputation.abs_diff(once.reputation) <= 1);
+    }
 }
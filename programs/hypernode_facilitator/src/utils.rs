@@ -0,0 +1,345 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_spl::token::{self, CloseAccount, TokenAccount, Transfer};
+
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::FacilitatorError;
+use crate::state::{FacilitatorConfig, Node, OracleAccount, PaymentIntent};
+
+/// `bps` basis points of `amount`, rounded down.
+pub fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    let share = u128::from(amount) * u128::from(bps) / u128::from(BPS_DENOMINATOR);
+    u64::try_from(share).map_err(|_| error!(FacilitatorError::MathOverflow))
+}
+
+/// `2^(-2^-i)` in 32.32 fixed point for `i = 1..=20`: the factor for each
+/// binary digit of a fraction of a half-life.
+const HALF_LIFE_ROOTS: [u128; 20] = [
+    3_037_000_500,
+    3_611_622_603,
+    3_938_502_376,
+    4_112_874_773,
+    4_202_935_003,
+    4_248_701_965,
+    4_271_771_996,
+    4_283_353_945,
+    4_289_156_690,
+    4_292_061_010,
+    4_293_513_907,
+    4_294_240_540,
+    4_294_603_903,
+    4_294_785_595,
+    4_294_876_445,
+    4_294_921_870,
+    4_294_944_583,
+    4_294_955_939,
+    4_294_961_618,
+    4_294_964_457,
+];
+
+/// `value * 2^(-elapsed / half_life)`: halved once per whole half-life,
+/// with the remainder applied one binary digit at a time. Negative
+/// `elapsed` counts as none.
+pub fn half_life_decay(value: u128, elapsed: i64, half_life: i64) -> u128 {
+    let elapsed = elapsed.max(0) as u128;
+    let half_life = half_life.max(1) as u128;
+    let halvings = elapsed / half_life;
+    if halvings >= 128 {
+        return 0;
+    }
+    let mut value = value >> halvings;
+    let digits = HALF_LIFE_ROOTS.len();
+    let fraction = ((elapsed % half_life) << digits) / half_life;
+    for (i, root) in HALF_LIFE_ROOTS.iter().enumerate() {
+        if fraction & (1 << (digits - 1 - i)) != 0 {
+            value = value * root >> 32;
+        }
+    }
+    value
+}
+
+/// PDA seed for a node or intent id. A seed is at most 32 bytes, so the id
+/// is hashed rather than used as is.
+pub fn id_seed(id: &str) -> [u8; 32] {
+    hash(id.as_bytes()).to_bytes()
+}
+
+/// How a settled escrow is divided.
+pub struct SettlementSplit {
+    /// Protocol cut, plus any oracle shares forfeited by removed attestors.
+    pub fee: u64,
+    /// Part of `fee` that overflows the treasury threshold and goes to the
+    /// insurance vault.
+    pub insurance_fee: u64,
+    /// Oracle cut credited to each attestor.
+    pub oracle_share: u64,
+    pub oracle_fee: u64,
+    /// Part of the node's share held back for missed heartbeats and sent to
+    /// the insurance vault.
+    pub uptime_withheld: u64,
+    pub node_amount: u64,
+}
+
+impl SettlementSplit {
+    /// The oracle cut is shared evenly between the attestors; rounding dust
+    /// stays with the node.
+    pub fn new(
+        config: &FacilitatorConfig,
+        amount: u64,
+        attestors: usize,
+        treasury_balance: u64,
+    ) -> Result<Self> {
+        let fee = config.protocol_fee(amount)?;
+        let treasury_room = config
+            .insurance_fee_threshold
+            .saturating_sub(treasury_balance);
+        let oracle_share = match attestors {
+            0 => 0,
+            n => config.oracle_fee(amount)? / n as u64,
+        };
+        let oracle_fee = oracle_share * attestors as u64;
+        Ok(Self {
+            fee,
+            insurance_fee: fee.saturating_sub(treasury_room),
+            oracle_share,
+            oracle_fee,
+            uptime_withheld: 0,
+            node_amount: amount - fee - oracle_fee,
+        })
+    }
+
+    /// Moves oracle shares nobody can claim into the protocol fee. The
+    /// treasury takes them whatever its balance.
+    pub fn forfeit_oracle_fees(&mut self, amount: u64) {
+        self.oracle_fee -= amount;
+        self.fee += amount;
+    }
+
+    /// Holds back the `uptime_reward_bps` part of the node's share in
+    /// proportion to the heartbeat epochs the node missed.
+    pub fn withhold_for_downtime(
+        &mut self,
+        config: &FacilitatorConfig,
+        uptime_bps: u16,
+    ) -> Result<()> {
+        let at_stake = bps_of(self.node_amount, config.uptime_reward_bps)?;
+        let downtime_bps = (BPS_DENOMINATOR - u64::from(uptime_bps)) as u16;
+        self.uptime_withheld = bps_of(at_stake, downtime_bps)?;
+        self.node_amount -= self.uptime_withheld;
+        Ok(())
+    }
+}
+
+/// Pays `payouts` out of an intent's escrow and closes it, handing the rent
+/// to `rent_destination`. Zero payouts are skipped. Whatever the escrow holds
+/// beyond the payouts, such as tokens sent to it directly, goes to
+/// `surplus_destination`, since the account only closes once empty.
+pub fn release_escrow<'info>(
+    intent: &PaymentIntent,
+    escrow: &Account<'info, TokenAccount>,
+    payouts: &[(AccountInfo<'info>, u64)],
+    surplus_destination: AccountInfo<'info>,
+    rent_destination: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[
+        b"escrow",
+        &id_seed(&intent.intent_id),
+        &[intent.escrow_bump],
+    ];
+    let paid = payouts.iter().map(|(_, amount)| amount).sum::<u64>();
+    let surplus = (surplus_destination, escrow.amount.saturating_sub(paid));
+    for (destination, amount) in payouts.iter().chain(std::iter::once(&surplus)) {
+        if *amount == 0 {
+            continue;
+        }
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                Transfer {
+                    from: escrow.to_account_info(),
+                    to: destination.clone(),
+                    authority: escrow.to_account_info(),
+                },
+                &[seeds],
+            ),
+            *amount,
+        )?;
+    }
+    token::close_account(CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: escrow.to_account_info(),
+            destination: rent_destination,
+            authority: escrow.to_account_info(),
+        },
+        &[seeds],
+    ))
+}
+
+/// Credits `share` to every attestor's `OracleAccount.pending_fees` and
+/// returns the total owed to attestors that have since been removed from
+/// the registry, which nobody can claim any more.
+///
+/// `signer` is the attesting oracle's own account when it is already loaded;
+/// the others are looked up in `remaining_accounts` at their
+/// `["oracle", authority]` address, which must be passed even once closed.
+pub fn credit_oracle_fees<'info>(
+    attestors: &[Pubkey],
+    share: u64,
+    mut signer: Option<&mut Account<'info, OracleAccount>>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<u64> {
+    if share == 0 {
+        return Ok(0);
+    }
+    let mut forfeited: u64 = 0;
+    for attestor in attestors {
+        if let Some(own) = signer.as_mut().filter(|own| own.authority == *attestor) {
+            own.pending_fees = own
+                .pending_fees
+                .checked_add(share)
+                .ok_or(FacilitatorError::MathOverflow)?;
+            continue;
+        }
+        let (address, _) =
+            Pubkey::find_program_address(&[b"oracle", attestor.as_ref()], &crate::ID);
+        let info = remaining_accounts
+            .iter()
+            .find(|info| *info.key == address)
+            .ok_or(FacilitatorError::MissingOracleAccount)?;
+        if info.owner != &crate::ID {
+            forfeited = forfeited
+                .checked_add(share)
+                .ok_or(FacilitatorError::MathOverflow)?;
+            continue;
+        }
+        let mut other = Account::<OracleAccount>::try_from(info)?;
+        other.pending_fees = other
+            .pending_fees
+            .checked_add(share)
+            .ok_or(FacilitatorError::MathOverflow)?;
+        other.exit(&crate::ID)?;
+    }
+    Ok(forfeited)
+}
+
+/// Counts the oracles that co-signed an instruction. They are passed in
+/// `remaining_accounts` as `[oracle_account, authority]` pairs, with the
+/// authority signing; each must be allowed to attest and bonded, and no
+/// oracle may sign twice, even under both keys of a rotation.
+pub fn count_oracle_signers<'info>(
+    config: &FacilitatorConfig,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<usize> {
+    let mut signers: Vec<Pubkey> = Vec::new();
+    for pair in remaining_accounts.chunks_exact(2) {
+        let oracle = Account::<OracleAccount>::try_from(&pair[0])?;
+        require!(
+            pair[1].is_signer && *pair[1].key == oracle.authority,
+            FacilitatorError::UnauthorizedOracle
+        );
+        require!(oracle.can_attest(now), FacilitatorError::UnauthorizedOracle);
+        require!(
+            oracle.meets_bond_minimum(config),
+            FacilitatorError::InsufficientOracleBond
+        );
+        require!(
+            !signers.iter().any(|signer| oracle.is_same_oracle(signer)),
+            FacilitatorError::DuplicateAttestation
+        );
+        signers.push(oracle.authority);
+    }
+    Ok(signers.len())
+}
+
+/// Moves `amount` of slashed stake from a node's vault to the insurance
+/// vault.
+pub fn insure_slashed_stake<'info>(
+    node: &Account<'info, Node>,
+    vault: &Account<'info, TokenAccount>,
+    insurance_vault: &Account<'info, TokenAccount>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let node_key = node.key();
+    let seeds: &[&[u8]] = &[b"vault", node_key.as_ref(), &[node.vault_bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program,
+            Transfer {
+                from: vault.to_account_info(),
+                to: insurance_vault.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )
+}
+
+/// Decodes a 64-character hex string (as produced by SHA-256 tooling
+/// off-chain) into its 32 raw bytes.
+pub fn parse_hash_hex(hex: &str) -> Option<[u8; 32]> {
+    let bytes = hex.as_bytes();
+    if bytes.len() != 64 {
+        return None;
+    }
+
+    let mut out = [0u8; 32];
+    for (i, pair) in bytes.chunks_exact(2).enumerate() {
+        let hi = (pair[0] as char).to_digit(16)?;
+        let lo = (pair[1] as char).to_digit(16)?;
+        out[i] = (hi * 16 + lo) as u8;
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALF_LIFE: i64 = 30 * 86400;
+
+    #[test]
+    fn half_life_roots_match_their_definition() {
+        for (i, root) in HALF_LIFE_ROOTS.iter().enumerate() {
+            let exponent = -(0.5f64).powi(i as i32 + 1);
+            let expected = (2f64.powf(exponent) * 4_294_967_296.0).round() as u128;
+            assert_eq!(*root, expected, "root {}", i + 1);
+        }
+    }
+
+    #[test]
+    fn half_life_decay_halves_per_half_life() {
+        let value = 1u128 << 40;
+        assert_eq!(half_life_decay(value, 0, HALF_LIFE), value);
+        assert_eq!(half_life_decay(value, -HALF_LIFE, HALF_LIFE), value);
+        assert_eq!(half_life_decay(value, HALF_LIFE, HALF_LIFE), value >> 1);
+        assert_eq!(half_life_decay(value, 3 * HALF_LIFE, HALF_LIFE), value >> 3);
+        assert_eq!(half_life_decay(value, 128 * HALF_LIFE, HALF_LIFE), 0);
+    }
+
+    #[test]
+    fn half_life_decay_follows_the_exponential_between_halvings() {
+        let value = 1u128 << 40;
+        for elapsed in [1, 3_600, HALF_LIFE / 3, HALF_LIFE / 2, HALF_LIFE + 12_345] {
+            let exact = value as f64 * 2f64.powf(-(elapsed as f64) / HALF_LIFE as f64);
+            let decayed = half_life_decay(value, elapsed, HALF_LIFE) as f64;
+            assert!((decayed - exact).abs() / exact < 1e-5, "elapsed {elapsed}");
+        }
+    }
+
+    #[test]
+    fn half_life_decay_barely_depends_on_the_step_size() {
+        let value = 1u128 << 40;
+        let mut stepped = value;
+        for _ in 0..720 {
+            stepped = half_life_decay(stepped, 3_600, HALF_LIFE);
+        }
+        let direct = half_life_decay(value, 720 * 3_600, HALF_LIFE);
+        // Each step rounds its fraction of a half-life down to 2^-20.
+        assert!(stepped.abs_diff(direct) * 1_000 < direct);
+    }
+}
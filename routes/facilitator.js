@@ -32,13 +32,25 @@ const x402Adapter = createX402SolanaAdapter({
  */
 router.post('/register-node', requireAuth, async (req, res) => {
   try {
-    const { nodeId, stakeAmount } = req.body;
+    const { nodeId, stakeAmount, endpoint, hardware } = req.body;
     const walletAddress = req.user.wallet;
 
     // Register node on-chain via Facilitator
     const result = await facilitatorClient.registerNode(
       walletAddress,
-      nodeId,
+      {
+        nodeId,
+        endpoint: endpoint || `https://${req.body.hostname}`,
+        region: req.body.location || '',
+        hardware: {
+          cpuCores: 0,
+          ramGb: 0,
+          gpuModel: '',
+          gpuCount: 0,
+          vramGb: 0,
+          ...hardware,
+        },
+      },
       stakeAmount || 0
     );
 
@@ -1,28 +1,1268 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import * as assert from "assert";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+  transfer,
+} from "@solana/spl-token";
+import { createHash } from "crypto";
 import { HypernodeFacilitator } from "../target/types/hypernode_facilitator";
 
+// Node and intent ids are hashed into their PDA seeds.
+const idSeed = (id: string) => createHash("sha256").update(id).digest();
+const hashHex = (data: string) => createHash("sha256").update(data).digest("hex");
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
 describe("hypernode-facilitator", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
 
   const program = anchor.workspace.HypernodeFacilitator as Program<HypernodeFacilitator>;
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  const wallet = provider.wallet.publicKey;
 
-  it("Registers a new node", async () => {
-    const [nodePda, bump] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("node"), provider.wallet.publicKey.toBuffer()],
-      program.programId
+  const pda = (...seeds: Buffer[]) =>
+    anchor.web3.PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+  const configPda = pda(Buffer.from("config"));
+  const rewardVaultPda = pda(Buffer.from("reward_vault"));
+  const treasuryPda = pda(Buffer.from("treasury"));
+  const insuranceVaultPda = pda(Buffer.from("insurance"));
+  const eventAuthority = pda(Buffer.from("__event_authority"));
+
+  const nodeId = "node-abc-123";
+  const nodePda = pda(Buffer.from("node"), idSeed(nodeId));
+  const vaultPda = pda(Buffer.from("vault"), nodePda.toBuffer());
+  const oraclePda = pda(Buffer.from("oracle"), wallet.toBuffer());
+
+  const configParams = {
+    stakeMinimum: new BN(1_000_000_000),
+    maxPaymentTimeout: new BN(86400),
+    unbondingPeriod: new BN(7 * 86400),
+    maxDisputeWindow: new BN(86400),
+    feeBps: 500,
+    insuranceFeeThreshold: new BN("1000000000000000000"),
+    oracleFeeBps: 250,
+    oracleMinBond: new BN(0),
+    oracleRotationOverlap: new BN(3600),
+    oracleQuorum: 1,
+    slashBps: 1000,
+    jailThreshold: 3,
+    jailCooldown: new BN(86400),
+    // Short epochs keep the uptime tests quick; the generous miss limit keeps
+    // nodes that never send a heartbeat live for the whole run.
+    heartbeatEpochSlots: new BN(20),
+    maxMissedHeartbeats: 200,
+    uptimeRewardBps: 0,
+    arbiters: [wallet],
+    paused: false,
+  };
+
+  let hyperMint: anchor.web3.PublicKey;
+  // The provider wallet plays node owner, client, oracle and arbiter.
+  let walletTokenAccount: anchor.web3.PublicKey;
+  let secondOracle: anchor.web3.Keypair;
+
+  const balance = async (address: anchor.web3.PublicKey) =>
+    (await getAccount(provider.connection, address)).amount;
+
+  // Sends tokens straight into a program-owned account, as anyone can.
+  const donate = (destination: anchor.web3.PublicKey, amount: number) =>
+    transfer(provider.connection, payer, walletTokenAccount, destination, payer, amount);
+
+  // Unix time of the cluster clock, which can drift from the local one.
+  const chainTime = async () => {
+    const clock = await provider.connection.getAccountInfo(anchor.web3.SYSVAR_CLOCK_PUBKEY);
+    return Number(clock!.data.readBigInt64LE(32));
+  };
+
+  const waitUntil = async (unixTime: number) => {
+    while ((await chainTime()) <= unixTime) {
+      await sleep(1000);
+    }
+  };
+
+  const waitForSlot = async (slot: number) => {
+    while ((await provider.connection.getSlot()) < slot) {
+      await sleep(400);
+    }
+  };
+
+  const slotOf = async (signature: string) =>
+    (await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    }))!.slot;
+
+  const expectError = async (call: Promise<unknown>, code: string) => {
+    try {
+      await call;
+    } catch (err) {
+      assert.strictEqual((err as anchor.AnchorError).error?.errorCode?.code, code);
+      return;
+    }
+    assert.fail(`expected ${code}`);
+  };
+
+  const intentAccounts = (intentId: string) => ({
+    paymentIntent: pda(Buffer.from("intent"), idSeed(intentId)),
+    escrow: pda(Buffer.from("escrow"), idSeed(intentId)),
+    usageProof: pda(Buffer.from("proof"), idSeed(intentId)),
+  });
+
+  const authorize = async (
+    intentId: string,
+    amount: number,
+    { expiresIn = 3600, disputeWindow = 0 } = {}
+  ) => {
+    const { paymentIntent, escrow } = intentAccounts(intentId);
+    await program.methods
+      .authorizePayment({
+        intentId,
+        amount: new BN(amount),
+        expiresAt: new BN((await chainTime()) + expiresIn),
+        disputeWindow: new BN(disputeWindow),
+      })
+      .accounts({
+        paymentIntent,
+        client: wallet,
+        escrow,
+        clientTokenAccount: walletTokenAccount,
+        config: configPda,
+        hyperMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+  };
+
+  const acceptJob = (intentId: string) =>
+    program.methods
+      .acceptJob()
+      .accounts({
+        node: nodePda,
+        paymentIntent: intentAccounts(intentId).paymentIntent,
+        config: configPda,
+        operator: wallet,
+        client: wallet,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+  const refund = (intentId: string, node: anchor.web3.PublicKey | null = null) => {
+    const { paymentIntent, escrow } = intentAccounts(intentId);
+    return program.methods
+      .refundExpired()
+      .accounts({
+        paymentIntent,
+        client: wallet,
+        escrow,
+        clientTokenAccount: walletTokenAccount,
+        node,
+        config: configPda,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+  };
+
+  const operatorAccountOf = (owner: anchor.web3.PublicKey) =>
+    pda(Buffer.from("operator"), owner.toBuffer());
+
+  // Registers another node under `owner` (the provider wallet by default).
+  const registerNode = async (id: string, owner?: anchor.web3.Keypair) => {
+    const user = owner?.publicKey ?? wallet;
+    const node = pda(Buffer.from("node"), idSeed(id));
+    const vault = pda(Buffer.from("vault"), node.toBuffer());
+    await program.methods
+      .registerNode({
+        nodeId: id,
+        endpoint: `https://${id}.example.com`,
+        region: "us-east",
+        hardware: { cpuCores: 8, ramGb: 32, gpuModel: "", gpuCount: 0, vramGb: 0 },
+      })
+      .accounts({
+        node,
+        operatorAccount: operatorAccountOf(user),
+        vault,
+        config: configPda,
+        user,
+        hyperMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .signers(owner ? [owner] : [])
+      .rpc();
+    return { node, vault };
+  };
+
+  const setNodeActive = (node: anchor.web3.PublicKey, active: boolean) => {
+    const call = active
+      ? program.methods.reactivateNode().accounts({
+          node,
+          config: configPda,
+          owner: wallet,
+          eventAuthority,
+          program: program.programId,
+        })
+      : program.methods.deactivateNode().accounts({
+          node,
+          owner: wallet,
+          eventAuthority,
+          program: program.programId,
+        });
+    return call.rpc();
+  };
+
+  const setOperator = (node: anchor.web3.PublicKey, operator: anchor.web3.PublicKey) =>
+    program.methods
+      .setOperator(operator)
+      .accounts({ node, owner: wallet, eventAuthority, program: program.programId })
+      .rpc();
+
+  // Claims from the main node as `owner` (the provider wallet by default).
+  const claimRewards = (amount: number, owner?: anchor.web3.Keypair) =>
+    program.methods
+      .claimRewards(new BN(amount))
+      .accounts({
+        node: nodePda,
+        config: configPda,
+        rewardVault: rewardVaultPda,
+        ownerTokenAccount: walletTokenAccount,
+        owner: owner?.publicKey ?? wallet,
+        hyperMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .signers(owner ? [owner] : [])
+      .rpc();
+
+  const cancel = (intentId: string, node: anchor.web3.PublicKey | null = null) => {
+    const { paymentIntent, escrow } = intentAccounts(intentId);
+    return program.methods
+      .cancelPayment()
+      .accounts({
+        paymentIntent,
+        client: wallet,
+        escrow,
+        clientTokenAccount: walletTokenAccount,
+        node,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+  };
+
+  const oracleAccountOf = (authority: anchor.web3.PublicKey) =>
+    pda(Buffer.from("oracle"), authority.toBuffer());
+
+  const addOracle = (authority: anchor.web3.PublicKey) => {
+    const oracleAccount = oracleAccountOf(authority);
+    return program.methods
+      .addOracle(authority)
+      .accounts({
+        oracleAccount,
+        bondVault: pda(Buffer.from("oracle_bond"), oracleAccount.toBuffer()),
+        config: configPda,
+        admin: wallet,
+        hyperMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+  };
+
+  // Applies `overrides` on top of the suite's config; no argument restores it.
+  const updateConfig = (overrides: Partial<typeof configParams> = {}) =>
+    program.methods
+      .updateConfig({ ...configParams, ...overrides })
+      .accounts({ config: configPda, admin: wallet })
+      .rpc();
+
+  // A keypair with SOL for fees, for roles the provider wallet cannot play.
+  const fundedKeypair = async () => {
+    const keypair = anchor.web3.Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(
+      keypair.publicKey,
+      2 * anchor.web3.LAMPORTS_PER_SOL
     );
+    await provider.connection.confirmTransaction({
+      signature,
+      ...(await provider.connection.getLatestBlockhash()),
+    });
+    return keypair;
+  };
+
+  // Attests as `oracle` (the provider wallet by default). An attestation
+  // that completes a quorum must also list the earlier attestors.
+  const submitProof = (
+    intentId: string,
+    {
+      executionHash = hashHex(`${intentId}-output`),
+      oracle = undefined as anchor.web3.Keypair | undefined,
+      attestors = [] as anchor.web3.PublicKey[],
+    } = {}
+  ) => {
+    const { paymentIntent, escrow, usageProof } = intentAccounts(intentId);
+    const authority = oracle?.publicKey ?? wallet;
+    return program.methods
+      .submitUsageProof({
+        intentId,
+        nodeId,
+        executionHash,
+        logsHash: hashHex(`${intentId}-logs`),
+      })
+      .accounts({
+        usageProof,
+        paymentIntent,
+        node: nodePda,
+        config: configPda,
+        oracleAccount: oracleAccountOf(authority),
+        oracle: authority,
+        escrow,
+        rewardVault: rewardVaultPda,
+        treasury: treasuryPda,
+        insuranceVault: insuranceVaultPda,
+        client: wallet,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(
+        attestors.map((attestor) => ({
+          pubkey: oracleAccountOf(attestor),
+          isWritable: true,
+          isSigner: false,
+        }))
+      )
+      .signers(oracle ? [oracle] : [])
+      .rpc();
+  };
+
+  const slash = (intentId: string) => {
+    const { paymentIntent, usageProof } = intentAccounts(intentId);
+    return program.methods
+      .slashNode(hashHex(`${intentId}-evidence`))
+      .accounts({
+        paymentIntent,
+        usageProof,
+        node: nodePda,
+        vault: vaultPda,
+        config: configPda,
+        insuranceVault: insuranceVaultPda,
+        slasher: wallet,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+  };
+
+  const resolve = (intentId: string, { clientBps = 10_000, slashAmount = 0 } = {}) => {
+    const { paymentIntent, escrow, usageProof } = intentAccounts(intentId);
+    return program.methods
+      .resolveDispute({
+        clientBps,
+        slashAmount: new BN(slashAmount),
+        resolutionHash: hashHex(`${intentId}-resolution`),
+      })
+      .accounts({
+        paymentIntent,
+        usageProof,
+        node: nodePda,
+        vault: vaultPda,
+        config: configPda,
+        escrow,
+        rewardVault: rewardVaultPda,
+        treasury: treasuryPda,
+        clientTokenAccount: walletTokenAccount,
+        client: wallet,
+        insuranceVault: insuranceVaultPda,
+        arbiter: wallet,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+  };
 
+  // Takes an intent through a verified proof, a client dispute and a full
+  // refund by the arbiters, leaving it as evidence for `slash_node`.
+  const resolveAgainstNode = async (intentId: string) => {
+    const { paymentIntent } = intentAccounts(intentId);
+    await authorize(intentId, 1_000_000_000, { disputeWindow: 3600 });
+    await submitProof(intentId);
     await program.methods
-      .registerNode()
+      .openDispute(hashHex(`${intentId}-complaint`))
+      .accounts({ paymentIntent, client: wallet, eventAuthority, program: program.programId })
+      .rpc();
+    await resolve(intentId);
+  };
+
+  it("Initializes the facilitator config", async () => {
+    hyperMint = await createMint(provider.connection, payer, payer.publicKey, null, 9);
+
+    const programData = anchor.web3.PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    )[0];
+
+    await program.methods
+      .initializeConfig(configParams)
+      .accounts({
+        config: configPda,
+        rewardVault: rewardVaultPda,
+        treasury: treasuryPda,
+        insuranceVault: insuranceVaultPda,
+        admin: wallet,
+        hyperMint,
+        program: program.programId,
+        programData,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    walletTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(provider.connection, payer, hyperMint, wallet)
+    ).address;
+    await mintTo(provider.connection, payer, hyperMint, walletTokenAccount, payer, 100_000_000_000);
+  });
+
+  it("Registers a new node", async () => {
+    const operatorPda = pda(Buffer.from("operator"), wallet.toBuffer());
+
+    await program.methods
+      .registerNode({
+        nodeId,
+        endpoint: "https://node-abc-123.example.com",
+        region: "eu-west",
+        hardware: {
+          cpuCores: 32,
+          ramGb: 128,
+          gpuModel: "RTX 4090",
+          gpuCount: 2,
+          vramGb: 24,
+        },
+      })
       .accounts({
         node: nodePda,
-        user: provider.wallet.publicKey,
+        operatorAccount: operatorPda,
+        vault: vaultPda,
+        config: configPda,
+        user: wallet,
+        hyperMint,
         systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
       })
       .rpc();
 
-    console.log("Node registered:", nodePda.toBase58());
+    const operator = await program.account.operatorAccount.fetch(operatorPda);
+    assert.strictEqual(operator.nodeCount, 1);
+    assert.ok(operator.owner.equals(wallet));
+
+    const node = await program.account.node.fetch(nodePda);
+    assert.ok(node.owner.equals(wallet));
+    assert.ok(node.operator.equals(wallet));
+    assert.strictEqual(node.nodeId, nodeId);
+    assert.strictEqual(node.endpoint, "https://node-abc-123.example.com");
+    assert.strictEqual(node.region, "eu-west");
+    assert.strictEqual(node.hardware.gpuModel, "RTX 4090");
+    assert.strictEqual(node.hardware.gpuCount, 2);
+    assert.ok(node.isActive);
+    assert.ok(!node.jailed);
+    assert.ok(node.stakedAmount.isZero());
+    assert.ok(node.pendingReward.isZero());
+    assert.strictEqual(node.reputation, 5000);
+    assert.strictEqual(node.openJobs, 0);
+  });
+
+  it("Stakes the node and adds two oracles", async () => {
+    await program.methods
+      .stake(new BN(10_000_000_000))
+      .accounts({
+        node: nodePda,
+        vault: vaultPda,
+        config: configPda,
+        ownerTokenAccount: walletTokenAccount,
+        owner: wallet,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+    await addOracle(wallet);
+    secondOracle = await fundedKeypair();
+    await addOracle(secondOracle.publicKey);
+
+    const node = await program.account.node.fetch(nodePda);
+    assert.strictEqual(node.stakedAmount.toString(), "10000000000");
+  });
+
+  it("Settles a payment on an oracle's proof and splits the fees", async () => {
+    const intentId = "intent-settle";
+    const { paymentIntent, escrow } = intentAccounts(intentId);
+    await authorize(intentId, 1_000_000_000);
+    assert.strictEqual((await balance(escrow)).toString(), "1000000000");
+
+    const treasuryBefore = await balance(treasuryPda);
+    const nodeBefore = await program.account.node.fetch(nodePda);
+    const oracleBefore = await program.account.oracleAccount.fetch(oraclePda);
+    await submitProof(intentId);
+
+    const intent = await program.account.paymentIntent.fetch(paymentIntent);
+    assert.deepStrictEqual(intent.status, { completed: {} });
+    assert.ok(intent.node!.equals(nodePda));
+    assert.strictEqual(await provider.connection.getAccountInfo(escrow), null);
+
+    assert.strictEqual(((await balance(treasuryPda)) - treasuryBefore).toString(), "50000000");
+    const oracle = await program.account.oracleAccount.fetch(oraclePda);
+    assert.strictEqual(oracle.pendingFees.sub(oracleBefore.pendingFees).toNumber(), 25_000_000);
+    const node = await program.account.node.fetch(nodePda);
+    assert.strictEqual(node.pendingReward.sub(nodeBefore.pendingReward).toNumber(), 925_000_000);
+    assert.strictEqual(node.jobsCompleted.toNumber(), nodeBefore.jobsCompleted.toNumber() + 1);
+  });
+
+  it("Settles an escrow that was sent extra tokens", async () => {
+    const intentId = "intent-dust";
+    const { paymentIntent, escrow } = intentAccounts(intentId);
+    await authorize(intentId, 1_000_000_000);
+    await donate(escrow, 1);
+
+    const treasuryBefore = await balance(treasuryPda);
+    await submitProof(intentId);
+
+    const intent = await program.account.paymentIntent.fetch(paymentIntent);
+    assert.deepStrictEqual(intent.status, { completed: {} });
+    assert.strictEqual(await provider.connection.getAccountInfo(escrow), null);
+    // The surplus goes to the treasury on top of the protocol fee.
+    assert.strictEqual(((await balance(treasuryPda)) - treasuryBefore).toString(), "50000001");
+  });
+
+  it("Rejects malformed and duplicate attestations", async () => {
+    const intentId = "intent-quorum";
+    const { paymentIntent } = intentAccounts(intentId);
+    await authorize(intentId, 1_000_000_000);
+    await expectError(
+      submitProof(intentId, { executionHash: "not-a-hash" }),
+      "InvalidExecutionHash"
+    );
+
+    await updateConfig({ oracleQuorum: 2 });
+    try {
+      await submitProof(intentId);
+      await expectError(submitProof(intentId), "DuplicateAttestation");
+      // Contradicting its own attestation must not let an oracle freeze the escrow.
+      await expectError(
+        submitProof(intentId, { executionHash: hashHex("other-output") }),
+        "DuplicateAttestation"
+      );
+    } finally {
+      await updateConfig();
+    }
+
+    const intent = await program.account.paymentIntent.fetch(paymentIntent);
+    assert.deepStrictEqual(intent.status, { authorized: {} });
+  });
+
+  it("Settles once a quorum of oracles agrees", async () => {
+    const intentId = "intent-quorum-settle";
+    const { paymentIntent } = intentAccounts(intentId);
+    const secondOraclePda = oracleAccountOf(secondOracle.publicKey);
+    await authorize(intentId, 1_000_000_000);
+    const oracleBefore = await program.account.oracleAccount.fetch(oraclePda);
+    const secondBefore = await program.account.oracleAccount.fetch(secondOraclePda);
+    const nodeBefore = await program.account.node.fetch(nodePda);
+
+    await updateConfig({ oracleQuorum: 2 });
+    try {
+      await submitProof(intentId);
+      const pending = await program.account.paymentIntent.fetch(paymentIntent);
+      assert.deepStrictEqual(pending.status, { authorized: {} });
+      await submitProof(intentId, { oracle: secondOracle, attestors: [wallet] });
+    } finally {
+      await updateConfig();
+    }
+
+    const intent = await program.account.paymentIntent.fetch(paymentIntent);
+    assert.deepStrictEqual(intent.status, { completed: {} });
+    // The 2.5% oracle fee is shared between the two attestors.
+    const oracle = await program.account.oracleAccount.fetch(oraclePda);
+    const second = await program.account.oracleAccount.fetch(secondOraclePda);
+    assert.strictEqual(oracle.pendingFees.sub(oracleBefore.pendingFees).toNumber(), 12_500_000);
+    assert.strictEqual(second.pendingFees.sub(secondBefore.pendingFees).toNumber(), 12_500_000);
+    const node = await program.account.node.fetch(nodePda);
+    assert.strictEqual(node.pendingReward.sub(nodeBefore.pendingReward).toNumber(), 925_000_000);
+  });
+
+  it("Holds a disputable payment until its window closes", async () => {
+    const intentId = "intent-window";
+    const { paymentIntent, escrow, usageProof } = intentAccounts(intentId);
+    await authorize(intentId, 1_000_000_000, { disputeWindow: 3600 });
+    await submitProof(intentId);
+
+    const intent = await program.account.paymentIntent.fetch(paymentIntent);
+    assert.deepStrictEqual(intent.status, { verified: {} });
+    assert.strictEqual((await program.account.node.fetch(nodePda)).openJobs, 1);
+
+    await expectError(
+      program.methods
+        .finalizeSettlement()
+        .accounts({
+          paymentIntent,
+          usageProof,
+          node: nodePda,
+          config: configPda,
+          escrow,
+          rewardVault: rewardVaultPda,
+          treasury: treasuryPda,
+          insuranceVault: insuranceVaultPda,
+          client: wallet,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          eventAuthority,
+          program: program.programId,
+        })
+        .rpc(),
+      "DisputeWindowOpen"
+    );
+  });
+
+  it("Refunds an expired payment, but not before it expires", async () => {
+    const intentId = "intent-refund";
+    const { paymentIntent, escrow } = intentAccounts(intentId);
+    await authorize(intentId, 1_000_000_000, { expiresIn: 2 });
+    await expectError(refund(intentId), "PaymentNotExpired");
+
+    const { expiresAt } = await program.account.paymentIntent.fetch(paymentIntent);
+    await waitUntil(expiresAt.toNumber());
+    const before = await balance(walletTokenAccount);
+    await refund(intentId);
+    assert.strictEqual(((await balance(walletTokenAccount)) - before).toString(), "1000000000");
+    assert.strictEqual(await provider.connection.getAccountInfo(paymentIntent), null);
+    assert.strictEqual(await provider.connection.getAccountInfo(escrow), null);
+  });
+
+  it("Jails a node that lets accepted jobs expire", async () => {
+    const { consecutiveFailures } = await program.account.node.fetch(nodePda);
+    const jailParams = { jailThreshold: consecutiveFailures + 1, jailCooldown: new BN(5) };
+    await updateConfig(jailParams);
+    try {
+      const intentId = "intent-jail";
+      const { paymentIntent } = intentAccounts(intentId);
+      await authorize(intentId, 1_000_000_000, { expiresIn: 2 });
+      const openJobs = (await program.account.node.fetch(nodePda)).openJobs;
+      await acceptJob(intentId);
+      assert.strictEqual((await program.account.node.fetch(nodePda)).openJobs, openJobs + 1);
+
+      const { expiresAt } = await program.account.paymentIntent.fetch(paymentIntent);
+      await waitUntil(expiresAt.toNumber());
+      // The crank cannot leave the accepting node out to spare it.
+      await expectError(refund(intentId), "MissingNodeAccount");
+      await refund(intentId, nodePda);
+
+      const node = await program.account.node.fetch(nodePda);
+      assert.ok(node.jailed);
+      assert.strictEqual(node.consecutiveFailures, consecutiveFailures + 1);
+      assert.strictEqual(node.openJobs, openJobs);
+
+      const unjail = () =>
+        program.methods
+          .unjail()
+          .accounts({
+            node: nodePda,
+            config: configPda,
+            owner: wallet,
+            eventAuthority,
+            program: program.programId,
+          })
+          .rpc();
+      await expectError(unjail(), "JailCooldownActive");
+      await waitUntil(node.jailedAt.toNumber() + 5);
+      await updateConfig({ ...jailParams, stakeMinimum: new BN("1000000000000000") });
+      await expectError(unjail(), "InsufficientNodeStake");
+      await updateConfig(jailParams);
+      await unjail();
+
+      const unjailed = await program.account.node.fetch(nodePda);
+      assert.ok(!unjailed.jailed);
+      assert.strictEqual(unjailed.consecutiveFailures, 0);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it("Slashes a node once per resolved dispute", async () => {
+    const intentId = "intent-slash";
+    const clientBefore = await balance(walletTokenAccount);
+    const openJobs = (await program.account.node.fetch(nodePda)).openJobs;
+    await resolveAgainstNode(intentId);
+    assert.strictEqual(await balance(walletTokenAccount), clientBefore);
+
+    const before = await program.account.node.fetch(nodePda);
+    assert.strictEqual(before.openJobs, openJobs);
+    const insuranceBefore = await balance(insuranceVaultPda);
+    await slash(intentId);
+
+    const node = await program.account.node.fetch(nodePda);
+    const expected = before.stakedAmount.muln(1000).divn(10_000);
+    assert.strictEqual(node.stakedAmount.toString(), before.stakedAmount.sub(expected).toString());
+    assert.strictEqual(node.slashCount, before.slashCount + 1);
+    assert.strictEqual(
+      ((await balance(insuranceVaultPda)) - insuranceBefore).toString(),
+      expected.toString()
+    );
+
+    await expectError(slash(intentId), "SlashEvidenceUsed");
+  });
+
+  it("Sizes a slash on active plus unbonding stake", async () => {
+    await program.methods
+      .requestUnstake(new BN(6_000_000_000))
+      .accounts({
+        node: nodePda,
+        config: configPda,
+        owner: wallet,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+    const intentId = "intent-slash-unbonding";
+    await resolveAgainstNode(intentId);
+
+    const before = await program.account.node.fetch(nodePda);
+    assert.ok(!before.unbondingAmount.isZero());
+    await slash(intentId);
+
+    // The penalty counts the unbonding stake but is drawn from the active
+    // stake first.
+    const node = await program.account.node.fetch(nodePda);
+    const expected = before.stakedAmount.add(before.unbondingAmount).muln(1000).divn(10_000);
+    assert.strictEqual(node.stakedAmount.toString(), before.stakedAmount.sub(expected).toString());
+    assert.strictEqual(node.unbondingAmount.toString(), before.unbondingAmount.toString());
+  });
+
+  it("Releases unstaked tokens only after the unbonding period", async () => {
+    const { node, vault } = await registerNode("node-unstake");
+    await program.methods
+      .stake(configParams.stakeMinimum)
+      .accounts({
+        node,
+        vault,
+        config: configPda,
+        ownerTokenAccount: walletTokenAccount,
+        owner: wallet,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+    await setNodeActive(node, false);
+
+    await updateConfig({ unbondingPeriod: new BN(3) });
+    try {
+      await program.methods
+        .requestUnstake(new BN(1))
+        .accounts({
+          node,
+          config: configPda,
+          owner: wallet,
+          eventAuthority,
+          program: program.programId,
+        })
+        .rpc();
+    } finally {
+      await updateConfig();
+    }
+    // Stake on its way out no longer counts toward the minimum.
+    await expectError(setNodeActive(node, true), "InsufficientNodeStake");
+
+    const withdraw = () =>
+      program.methods
+        .withdrawUnstaked()
+        .accounts({
+          node,
+          vault,
+          ownerTokenAccount: walletTokenAccount,
+          owner: wallet,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          eventAuthority,
+          program: program.programId,
+        })
+        .rpc();
+    await expectError(withdraw(), "UnbondingInProgress");
+
+    const { unbondingReadyAt } = await program.account.node.fetch(node);
+    await waitUntil(unbondingReadyAt.toNumber());
+    const walletBefore = await balance(walletTokenAccount);
+    await withdraw();
+    assert.strictEqual(((await balance(walletTokenAccount)) - walletBefore).toString(), "1");
+    const withdrawn = await program.account.node.fetch(node);
+    assert.ok(withdrawn.unbondingAmount.isZero());
+    assert.strictEqual(
+      withdrawn.stakedAmount.toString(),
+      configParams.stakeMinimum.subn(1).toString()
+    );
+    await expectError(withdraw(), "NothingToWithdraw");
+  });
+
+  it("Does not slash twice on a conflicted intent", async () => {
+    const intentId = "intent-conflict-slash";
+    const { paymentIntent } = intentAccounts(intentId);
+    await authorize(intentId, 1_000_000_000);
+    await updateConfig({ oracleQuorum: 2 });
+    try {
+      await submitProof(intentId);
+      await submitProof(intentId, {
+        executionHash: hashHex("other-output"),
+        oracle: secondOracle,
+      });
+    } finally {
+      await updateConfig();
+    }
+    assert.deepStrictEqual(
+      (await program.account.paymentIntent.fetch(paymentIntent)).status,
+      { disputed: {} }
+    );
+
+    await slash(intentId);
+    const { slashCount } = await program.account.node.fetch(nodePda);
+    await expectError(resolve(intentId, { slashAmount: 1 }), "SlashEvidenceUsed");
+
+    // Resolving without a slash keeps the intent marked as used.
+    await resolve(intentId, { clientBps: 0 });
+    const intent = await program.account.paymentIntent.fetch(paymentIntent);
+    assert.deepStrictEqual(intent.status, { resolved: {} });
+    assert.ok(intent.slashed);
+    await expectError(slash(intentId), "SlashEvidenceUsed");
+    assert.strictEqual((await program.account.node.fetch(nodePda)).slashCount, slashCount);
+  });
+
+  it("Compensates a client only when the node lost the dispute", async () => {
+    const compensate = (intentId: string, amount: number) =>
+      program.methods
+        .compensateClient(new BN(amount))
+        .accounts({
+          paymentIntent: intentAccounts(intentId).paymentIntent,
+          config: configPda,
+          insuranceVault: insuranceVaultPda,
+          clientTokenAccount: walletTokenAccount,
+          arbiter: wallet,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          eventAuthority,
+          program: program.programId,
+        })
+        .rpc();
+
+    // "intent-slash" was fully refunded to the client by the arbiters.
+    const walletBefore = await balance(walletTokenAccount);
+    const insuranceBefore = await balance(insuranceVaultPda);
+    await compensate("intent-slash", 100_000_000);
+    assert.strictEqual(
+      ((await balance(walletTokenAccount)) - walletBefore).toString(),
+      "100000000"
+    );
+    assert.strictEqual(
+      (insuranceBefore - (await balance(insuranceVaultPda))).toString(),
+      "100000000"
+    );
+    await expectError(compensate("intent-slash", 1_000_000_000), "CompensationExceeded");
+
+    // "intent-conflict-slash" was resolved in the node's favour.
+    await expectError(compensate("intent-conflict-slash", 1), "NodeWonDispute");
+  });
+
+  it("Counts one heartbeat per epoch and marks silent nodes offline", async () => {
+    const epochSlots = configParams.heartbeatEpochSlots.toNumber();
+    const epochOf = (slot: number) => Math.floor(slot / epochSlots);
+    const heartbeat = () =>
+      program.methods
+        .heartbeat()
+        .accounts({
+          node: nodePda,
+          config: configPda,
+          operator: wallet,
+          eventAuthority,
+          program: program.programId,
+        })
+        .rpc();
+    const markOffline = () =>
+      program.methods
+        .markNodeOffline()
+        .accounts({ node: nodePda, config: configPda, eventAuthority, program: program.programId })
+        .rpc();
+
+    const before = await program.account.node.fetch(nodePda);
+    await waitForSlot((epochOf(before.lastHeartbeatSlot.toNumber()) + 1) * epochSlots);
+    const first = await slotOf(await heartbeat());
+    const second = await slotOf(await heartbeat());
+    const node = await program.account.node.fetch(nodePda);
+    // Only the first heartbeat of an epoch counts.
+    const counted = 1 + (epochOf(second) > epochOf(first) ? 1 : 0);
+    assert.strictEqual(node.uptimeEpochs.toNumber(), before.uptimeEpochs.toNumber() + counted);
+    assert.strictEqual(node.lastHeartbeatSlot.toNumber(), second);
+
+    // Uptime already recorded is counted in epochs of this length.
+    await expectError(
+      updateConfig({ heartbeatEpochSlots: new BN(2 * epochSlots) }),
+      "InvalidConfig"
+    );
+
+    await expectError(markOffline(), "HeartbeatCurrent");
+    await updateConfig({ maxMissedHeartbeats: 1 });
+    try {
+      await waitForSlot(second + epochSlots);
+      await markOffline();
+      assert.ok(!(await program.account.node.fetch(nodePda)).isActive);
+      await expectError(setNodeActive(nodePda, true), "NodeOffline");
+      await heartbeat();
+      await setNodeActive(nodePda, true);
+    } finally {
+      await updateConfig();
+    }
+    assert.ok((await program.account.node.fetch(nodePda)).isActive);
+  });
+
+  it("Withholds the node's share for missed heartbeat epochs", async () => {
+    const intentId = "intent-uptime";
+    const epochSlots = configParams.heartbeatEpochSlots.toNumber();
+    await authorize(intentId, 1_000_000_000);
+    const before = await program.account.node.fetch(nodePda);
+    const insuranceBefore = await balance(insuranceVaultPda);
+    await updateConfig({ uptimeRewardBps: 10_000 });
+    let slot: number;
+    try {
+      slot = await slotOf(await submitProof(intentId));
+    } finally {
+      await updateConfig();
+    }
+
+    const epochs =
+      Math.floor(slot / epochSlots) -
+      Math.floor(before.registeredSlot.toNumber() / epochSlots) +
+      1;
+    const uptimeBps = Math.min(
+      Math.floor((before.uptimeEpochs.toNumber() * 10_000) / epochs),
+      10_000
+    );
+    // The payment less the 5% protocol fee and the 2.5% oracle fee.
+    const nodeShare = 925_000_000;
+    const withheld = Math.floor((nodeShare * (10_000 - uptimeBps)) / 10_000);
+    assert.ok(withheld > 0);
+
+    const node = await program.account.node.fetch(nodePda);
+    assert.strictEqual(
+      node.pendingReward.sub(before.pendingReward).toNumber(),
+      nodeShare - withheld
+    );
+    assert.strictEqual(
+      ((await balance(insuranceVaultPda)) - insuranceBefore).toString(),
+      withheld.toString()
+    );
+  });
+
+  it("Suspends, rotates and removes oracles", async () => {
+    const first = await fundedKeypair();
+    const rotated = await fundedKeypair();
+    await addOracle(first.publicKey);
+    const firstPda = oracleAccountOf(first.publicKey);
+    const rotatedPda = oracleAccountOf(rotated.publicKey);
+    const bondVaultOf = (oracleAccount: anchor.web3.PublicKey) =>
+      pda(Buffer.from("oracle_bond"), oracleAccount.toBuffer());
+
+    type OracleStatus = Parameters<typeof program.methods.setOracleStatus>[0];
+    const setStatus = (status: OracleStatus) =>
+      program.methods
+        .setOracleStatus(status)
+        .accounts({
+          oracleAccount: firstPda,
+          config: configPda,
+          admin: wallet,
+          eventAuthority,
+          program: program.programId,
+        })
+        .rpc();
+    const intentId = "intent-oracle-registry";
+    await authorize(intentId, 1_000_000_000);
+    await setStatus({ suspended: {} });
+    await expectError(submitProof(intentId, { oracle: first }), "UnauthorizedOracle");
+    await setStatus({ active: {} });
+    assert.deepStrictEqual((await program.account.oracleAccount.fetch(firstPda)).status, {
+      active: {},
+    });
+
+    const rotate = (from: anchor.web3.PublicKey, to: anchor.web3.PublicKey) =>
+      program.methods
+        .rotateOracle(to)
+        .accounts({
+          oracleAccount: oracleAccountOf(from),
+          newOracleAccount: oracleAccountOf(to),
+          newBondVault: bondVaultOf(oracleAccountOf(to)),
+          config: configPda,
+          admin: wallet,
+          hyperMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          eventAuthority,
+          program: program.programId,
+        })
+        .rpc();
+    await rotate(first.publicKey, rotated.publicKey);
+    const retiring = await program.account.oracleAccount.fetch(firstPda);
+    assert.ok(retiring.successor!.equals(rotated.publicKey));
+    assert.ok(retiring.retiresAt!.toNumber() > (await chainTime()));
+    const successor = await program.account.oracleAccount.fetch(rotatedPda);
+    assert.ok(successor.predecessor!.equals(first.publicKey));
+    const third = anchor.web3.Keypair.generate().publicKey;
+    await expectError(rotate(first.publicKey, third), "OracleRetired");
+    // The successor cannot hand over again while its predecessor still attests.
+    await expectError(rotate(rotated.publicKey, third), "RotationInProgress");
+
+    // During the overlap both keys attest, but as a single oracle.
+    await updateConfig({ oracleQuorum: 2 });
+    try {
+      await submitProof(intentId, { oracle: first });
+      await expectError(
+        submitProof(intentId, { oracle: rotated, attestors: [first.publicKey] }),
+        "DuplicateAttestation"
+      );
+    } finally {
+      await updateConfig();
+    }
+
+    // Stray tokens in the bond vault go to the treasury on removal.
+    const bondVault = bondVaultOf(rotatedPda);
+    await donate(bondVault, 1);
+    const treasuryBefore = await balance(treasuryPda);
+    await program.methods
+      .removeOracle()
+      .accounts({
+        oracleAccount: rotatedPda,
+        bondVault,
+        config: configPda,
+        treasury: treasuryPda,
+        admin: wallet,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+    assert.strictEqual(await provider.connection.getAccountInfo(rotatedPda), null);
+    assert.strictEqual(await provider.connection.getAccountInfo(bondVault), null);
+    assert.strictEqual(((await balance(treasuryPda)) - treasuryBefore).toString(), "1");
+  });
+
+  it("Lets the operator key run the node but not touch its funds or profile", async () => {
+    const operator = await fundedKeypair();
+    await setOperator(nodePda, operator.publicKey);
+    const heartbeat = (signer: anchor.web3.Keypair) =>
+      program.methods
+        .heartbeat()
+        .accounts({
+          node: nodePda,
+          config: configPda,
+          operator: signer.publicKey,
+          eventAuthority,
+          program: program.programId,
+        })
+        .signers([signer])
+        .rpc();
+    try {
+      const intentId = "intent-operator";
+      const { paymentIntent } = intentAccounts(intentId);
+      await authorize(intentId, 1_000_000_000);
+      await program.methods
+        .acceptJob()
+        .accounts({
+          node: nodePda,
+          paymentIntent,
+          config: configPda,
+          operator: operator.publicKey,
+          client: wallet,
+          eventAuthority,
+          program: program.programId,
+        })
+        .signers([operator])
+        .rpc();
+      const intent = await program.account.paymentIntent.fetch(paymentIntent);
+      assert.ok(intent.node!.equals(nodePda));
+      await cancel(intentId, nodePda);
+
+      await heartbeat(operator);
+      await expectError(heartbeat(payer), "NotNodeOperator");
+
+      await expectError(claimRewards(1, operator), "NotNodeOwner");
+      await expectError(
+        program.methods
+          .requestUnstake(new BN(1))
+          .accounts({
+            node: nodePda,
+            config: configPda,
+            owner: operator.publicKey,
+            eventAuthority,
+            program: program.programId,
+          })
+          .signers([operator])
+          .rpc(),
+        "NotNodeOwner"
+      );
+      await expectError(
+        program.methods
+          .updateNodeProfile({
+            nodeId,
+            endpoint: "https://operator.example.com",
+            region: "eu-west",
+            hardware: { cpuCores: 8, ramGb: 32, gpuModel: "", gpuCount: 0, vramGb: 0 },
+          })
+          .accounts({
+            node: nodePda,
+            owner: operator.publicKey,
+            eventAuthority,
+            program: program.programId,
+          })
+          .signers([operator])
+          .rpc(),
+        "NotNodeOwner"
+      );
+    } finally {
+      await setOperator(nodePda, wallet);
+    }
+  });
+
+  it("Pays out claimed rewards up to the node's balance", async () => {
+    const { pendingReward } = await program.account.node.fetch(nodePda);
+    assert.ok(!pendingReward.isZero());
+    await expectError(claimRewards(pendingReward.addn(1).toNumber()), "InsufficientBalance");
+
+    const walletBefore = await balance(walletTokenAccount);
+    const vaultBefore = await balance(rewardVaultPda);
+    await claimRewards(100_000_000);
+    assert.strictEqual(
+      ((await balance(walletTokenAccount)) - walletBefore).toString(),
+      "100000000"
+    );
+    assert.strictEqual((vaultBefore - (await balance(rewardVaultPda))).toString(), "100000000");
+    const node = await program.account.node.fetch(nodePda);
+    assert.strictEqual(
+      node.pendingReward.toString(),
+      pendingReward.subn(100_000_000).toString()
+    );
+  });
+
+  it("Cancels a payment only while it awaits a proof", async () => {
+    const intentId = "intent-cancel";
+    const { paymentIntent, escrow } = intentAccounts(intentId);
+    const walletBefore = await balance(walletTokenAccount);
+    await authorize(intentId, 1_000_000_000);
+    await cancel(intentId);
+    assert.strictEqual(await balance(walletTokenAccount), walletBefore);
+    assert.strictEqual(await provider.connection.getAccountInfo(paymentIntent), null);
+    assert.strictEqual(await provider.connection.getAccountInfo(escrow), null);
+
+    // "intent-window" has a verified proof and waits out its dispute window.
+    await expectError(cancel("intent-window", nodePda), "CannotCancel");
+  });
+
+  it("Transfers node ownership in two steps", async () => {
+    const { node } = await registerNode("node-transfer");
+    const operatorKey = anchor.web3.Keypair.generate().publicKey;
+    await setOperator(node, operatorKey);
+    const newOwner = await fundedKeypair();
+    const stranger = await fundedKeypair();
+
+    await program.methods
+      .proposeOwnerTransfer(newOwner.publicKey)
+      .accounts({ node, owner: wallet, eventAuthority, program: program.programId })
+      .rpc();
+    const accept = (signer: anchor.web3.Keypair) =>
+      program.methods
+        .acceptOwnerTransfer()
+        .accounts({
+          node,
+          previousOperatorAccount: operatorAccountOf(wallet),
+          newOperatorAccount: operatorAccountOf(signer.publicKey),
+          newOwner: signer.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          eventAuthority,
+          program: program.programId,
+        })
+        .signers([signer])
+        .rpc();
+    await expectError(accept(stranger), "NotPendingOwner");
+
+    const { nodeCount } = await program.account.operatorAccount.fetch(operatorAccountOf(wallet));
+    await accept(newOwner);
+
+    const transferred = await program.account.node.fetch(node);
+    assert.ok(transferred.owner.equals(newOwner.publicKey));
+    // The previous owner's operator key does not carry over.
+    assert.ok(transferred.operator.equals(newOwner.publicKey));
+    assert.strictEqual(transferred.pendingOwner, null);
+    const previous = await program.account.operatorAccount.fetch(operatorAccountOf(wallet));
+    assert.strictEqual(previous.nodeCount, nodeCount - 1);
+    const next = await program.account.operatorAccount.fetch(
+      operatorAccountOf(newOwner.publicKey)
+    );
+    assert.ok(next.owner.equals(newOwner.publicKey));
+    assert.strictEqual(next.nodeCount, 1);
+  });
+
+  it("Deactivates, reactivates and closes nodes", async () => {
+    await setNodeActive(nodePda, false);
+    assert.ok(!(await program.account.node.fetch(nodePda)).isActive);
+    await expectError(setNodeActive(nodePda, false), "NodeInactive");
+    await setNodeActive(nodePda, true);
+    assert.ok((await program.account.node.fetch(nodePda)).isActive);
+
+    const closeNode = (node: anchor.web3.PublicKey, vault: anchor.web3.PublicKey) =>
+      program.methods
+        .closeNode()
+        .accounts({
+          node,
+          vault,
+          operatorAccount: operatorAccountOf(wallet),
+          ownerTokenAccount: walletTokenAccount,
+          owner: wallet,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          eventAuthority,
+          program: program.programId,
+        })
+        .rpc();
+    await expectError(closeNode(nodePda, vaultPda), "NodeNotEmpty");
+
+    const { node, vault } = await registerNode("node-to-close");
+    await setNodeActive(node, false);
+    await expectError(setNodeActive(node, true), "InsufficientNodeStake");
+
+    // Tokens sent straight to the vault go back to the owner on close.
+    const walletBefore = await balance(walletTokenAccount);
+    await donate(vault, 1);
+    const { nodeCount } = await program.account.operatorAccount.fetch(operatorAccountOf(wallet));
+    await closeNode(node, vault);
+
+    assert.strictEqual(await provider.connection.getAccountInfo(node), null);
+    assert.strictEqual(await provider.connection.getAccountInfo(vault), null);
+    assert.strictEqual(await balance(walletTokenAccount), walletBefore);
+    const operator = await program.account.operatorAccount.fetch(operatorAccountOf(wallet));
+    assert.strictEqual(operator.nodeCount, nodeCount - 1);
   });
 });
@@ -210,7 +210,8 @@ export class X402SolanaAdapter {
         intent.client,
         intent.intentId,
         amountInLamports,
-        expiresAt
+        expiresAt,
+        intent.disputeWindow || 0
       );
 
       // 2. Store intent to prevent reuse